@@ -68,6 +68,10 @@
 //!
 //! This will select a field on a component and pass it to a [`Curve`] with a type that matches the field.
 //!
+//! When the field is only known at runtime, or is nested inside another field, an
+//! [`AnimatedPath`] can be used instead. It addresses the field with a [reflection path]
+//! such as `"color.0.red"`.
+//!
 //! ## Animatable Properties
 //!
 //! Animation of arbitrary aspects of entities can be accomplished using [`AnimatableProperty`] in
@@ -78,6 +82,7 @@
 //! This is the lowest-level option with the most control, but it is also the most complicated.
 //!
 //! [using a function]: bevy_math::curve::FunctionCurve
+//! [reflection path]: bevy_reflect::GetPath
 //! [translation component of a `Transform`]: bevy_transform::prelude::Transform::translation
 //! [`AnimationClip`]: crate::AnimationClip
 //! [there]: AnimatableProperty
@@ -86,6 +91,7 @@
 use core::{
     any::TypeId,
     fmt::{self, Debug, Formatter},
+    hash::BuildHasher,
     marker::PhantomData,
 };
 
@@ -100,8 +106,11 @@ use bevy_math::curve::{
     iterable::IterableCurve,
     Curve, Interval,
 };
-use bevy_platform_support::hash::Hashed;
-use bevy_reflect::{FromReflect, Reflect, Reflectable, TypeInfo, Typed};
+use bevy_platform_support::hash::{FixedHasher, Hashed};
+use bevy_reflect::{
+    Access, FromReflect, GetPath, OffsetAccess, ParsedPath, Reflect, ReflectPathError, Reflectable,
+    TypeInfo, Typed,
+};
 use bevy_render::mesh::morph::MorphWeights;
 use downcast_rs::{impl_downcast, Downcast};
 
@@ -281,6 +290,124 @@ impl<C: Typed, P, F: Fn(&mut C) -> &mut P + 'static> AnimatedField<C, P, F> {
     }
 }
 
+/// A (possibly nested) [`Component`] field that can be animated, addressed by a [reflection path].
+///
+/// Unlike [`AnimatedField`], which needs an accessor function known at compile time, this
+/// property can be created from a path string such as `"intensity"` or `"color.0.red"`, which
+/// makes it suitable for animation data authored outside of Rust code.
+///
+/// `C` is the component being animated and `A` is the type of the [`Animatable`] value found
+/// at the end of the path.
+///
+///     # use bevy_animation::animation_curves::AnimatedPath;
+///     # use bevy_ecs::component::Component;
+///     # use bevy_reflect::Reflect;
+///     #[derive(Component, Reflect)]
+///     struct Light {
+///         intensity: f32,
+///     }
+///
+///     let property = AnimatedPath::<Light, f32>::parse("intensity").unwrap();
+///
+/// Paths that consist of a single field access share their [`EvaluatorId`] with the equivalent
+/// [`AnimatedField`], so the two can be blended together.
+///
+/// [reflection path]: bevy_reflect::GetPath
+pub struct AnimatedPath<C, A> {
+    path: ParsedPath,
+    /// A pre-hashed (component-type-id, field identifier) pair, uniquely identifying a component
+    /// field. For single-field paths this is the reflected field index, otherwise it is a hash
+    /// of the whole path.
+    evaluator_id: Hashed<(TypeId, usize)>,
+    marker: PhantomData<fn() -> (C, A)>,
+}
+
+impl<C, A> Clone for AnimatedPath<C, A> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            evaluator_id: self.evaluator_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C, A> Debug for AnimatedPath<C, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimatedPath")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl<C: Typed, A> AnimatedPath<C, A> {
+    /// Creates a new [`AnimatedPath`] from an already parsed reflection path.
+    pub fn new(path: ParsedPath) -> Self {
+        let field_index = match (path.0.as_slice(), C::type_info()) {
+            (
+                [OffsetAccess {
+                    access: Access::Field(name),
+                    ..
+                }],
+                TypeInfo::Struct(struct_info),
+            ) => struct_info.index_of(name),
+            (
+                [OffsetAccess {
+                    access: Access::FieldIndex(index) | Access::TupleIndex(index),
+                    ..
+                }],
+                TypeInfo::Struct(_) | TypeInfo::TupleStruct(_),
+            ) => Some(*index),
+            _ => None,
+        };
+        let field_index = field_index.unwrap_or_else(|| FixedHasher.hash_one(&path) as usize);
+
+        Self {
+            path,
+            evaluator_id: Hashed::new((TypeId::of::<C>(), field_index)),
+            marker: PhantomData,
+        }
+    }
+
+    /// Parses `path` and creates a new [`AnimatedPath`] from it.
+    ///
+    /// The syntax of the path is described in the documentation of [`GetPath`].
+    ///
+    /// [`GetPath`]: bevy_reflect::GetPath
+    pub fn parse(path: &str) -> Result<Self, ReflectPathError<'_>> {
+        ParsedPath::parse(path).map(Self::new)
+    }
+
+    /// Returns the reflection path of the animated field.
+    pub fn path(&self) -> &ParsedPath {
+        &self.path
+    }
+}
+
+impl<C, A> AnimatableProperty for AnimatedPath<C, A>
+where
+    C: Component<Mutability = Mutable> + Reflect,
+    A: Animatable + Reflect,
+{
+    type Property = A;
+
+    fn get_mut<'a>(
+        &self,
+        entity: &'a mut AnimationEntityMut,
+    ) -> Result<&'a mut A, AnimationEvaluationError> {
+        let c = entity
+            .get_mut::<C>()
+            .ok_or_else(|| AnimationEvaluationError::ComponentNotPresent(TypeId::of::<C>()))?;
+        c.into_inner()
+            .path_mut::<A>(&self.path)
+            .map_err(|_| AnimationEvaluationError::PropertyNotPresent(TypeId::of::<A>()))
+    }
+
+    fn evaluator_id(&self) -> EvaluatorId {
+        EvaluatorId::ComponentField(&self.evaluator_id)
+    }
+}
+
 /// This trait collects the additional requirements on top of [`Curve<T>`] needed for a
 /// curve to be used as an [`AnimationCurve`].
 pub trait AnimationCompatibleCurve<T>: Curve<T> + Debug + Clone + Reflectable {}
@@ -432,6 +559,13 @@ impl<A: Animatable> AnimationCurveEvaluator for AnimatableCurveEvaluator<A> {
     }
 }
 
+impl<A: Animatable> AnimatableCurveEvaluator<A> {
+    /// Pops the value on top of the evaluation stack, if any.
+    pub(crate) fn pop_value(&mut self) -> Option<A> {
+        self.evaluator.stack.pop().map(|element| element.value)
+    }
+}
+
 /// This type allows an [`IterableCurve`] valued in `f32` to be used as an [`AnimationCurve`]
 /// that animates [morph weights].
 ///
@@ -1024,4 +1158,31 @@ mod tests {
         let _ = AnimatedField::new_unchecked("1", |b: &mut B| &mut b.1);
         let _ = AnimatedField::new_unchecked("2", |b: &mut B| &mut b.2);
     }
+
+    #[test]
+    fn test_animated_path_shares_evaluator_with_animated_field() {
+        #[derive(Clone, Debug, Component, Reflect)]
+        struct A {
+            x: f32,
+            nested: B,
+        }
+        #[derive(Clone, Debug, Component, Reflect)]
+        struct B(f32, f32);
+
+        let field = AnimatedField::new_unchecked("x", |a: &mut A| &mut a.x);
+        let path = AnimatedPath::<A, f32>::parse("x").unwrap();
+        let nested = AnimatedPath::<A, f32>::parse("nested.1").unwrap();
+
+        let EvaluatorId::ComponentField(field_id) = field.evaluator_id() else {
+            panic!("expected a component field evaluator id");
+        };
+        let EvaluatorId::ComponentField(path_id) = path.evaluator_id() else {
+            panic!("expected a component field evaluator id");
+        };
+        let EvaluatorId::ComponentField(nested_id) = nested.evaluator_id() else {
+            panic!("expected a component field evaluator id");
+        };
+        assert_eq!(field_id, path_id);
+        assert_ne!(path_id, nested_id);
+    }
 }
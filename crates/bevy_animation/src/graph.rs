@@ -127,6 +127,15 @@ pub struct AnimationGraph {
     /// Animation targets not in this collection are treated as though they
     /// don't belong to any mask groups.
     pub mask_groups: HashMap<AnimationTargetId, AnimationMask>,
+
+    /// Named parameters of the graph, each of which controls the weight of a
+    /// node.
+    ///
+    /// Parameters let game code drive a graph authored in an editor (for
+    /// example, a `"run"` parameter blending between walking and running)
+    /// without knowing the indices of the nodes involved. See
+    /// [`AnimationGraph::set_parameter`].
+    pub parameters: HashMap<String, AnimationNodeIndex>,
 }
 
 /// A [`Handle`] to the [`AnimationGraph`] to be used by the [`AnimationPlayer`](crate::AnimationPlayer) on the same entity.
@@ -365,6 +374,9 @@ pub struct SerializedAnimationGraph {
     pub root: NodeIndex,
     /// Corresponds to the `mask_groups` field on [`AnimationGraph`].
     pub mask_groups: HashMap<AnimationTargetId, AnimationMask>,
+    /// Corresponds to the `parameters` field on [`AnimationGraph`].
+    #[serde(default)]
+    pub parameters: HashMap<String, NodeIndex>,
 }
 
 /// A version of [`AnimationGraphNode`] suitable for serializing as an asset.
@@ -424,6 +436,7 @@ impl AnimationGraph {
             graph,
             root,
             mask_groups: HashMap::default(),
+            parameters: HashMap::default(),
         }
     }
 
@@ -661,6 +674,41 @@ impl AnimationGraph {
     pub fn add_target_to_mask_group(&mut self, target: AnimationTargetId, mask_group: u32) {
         *self.mask_groups.entry(target).or_default() |= 1 << mask_group;
     }
+
+    /// Adds a named parameter that controls the weight of the given node.
+    ///
+    /// If a parameter with the same name already exists, it's rebound to the
+    /// new node.
+    pub fn add_parameter(&mut self, name: impl Into<String>, node: AnimationNodeIndex) {
+        self.parameters.insert(name.into(), node);
+    }
+
+    /// Returns the current value of the named parameter, which is the weight of
+    /// the node it's bound to.
+    ///
+    /// Returns `None` if no such parameter exists.
+    pub fn parameter(&self, name: &str) -> Option<f32> {
+        let node = self.parameters.get(name)?;
+        self.get(*node).map(|node| node.weight)
+    }
+
+    /// Sets the value of the named parameter, updating the weight of the node
+    /// it's bound to.
+    ///
+    /// Returns true if the parameter was found or false if no such parameter
+    /// exists.
+    pub fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        let Some(&node) = self.parameters.get(name) else {
+            return false;
+        };
+        match self.get_mut(node) {
+            Some(node) => {
+                node.weight = value;
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl AnimationGraphNode {
@@ -779,6 +827,7 @@ impl AssetLoader for AnimationGraphAssetLoader {
             ),
             root: serialized_animation_graph.root,
             mask_groups: serialized_animation_graph.mask_groups,
+            parameters: serialized_animation_graph.parameters,
         })
     }
 
@@ -814,6 +863,7 @@ impl From<AnimationGraph> for SerializedAnimationGraph {
             ),
             root: animation_graph.root,
             mask_groups: animation_graph.mask_groups,
+            parameters: animation_graph.parameters,
         }
     }
 }
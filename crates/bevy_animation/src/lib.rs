@@ -58,7 +58,8 @@ pub mod prelude {
 }
 
 use crate::{
-    animation_curves::AnimationCurve,
+    animatable::Animatable,
+    animation_curves::{AnimatableCurveEvaluator, AnimationCurve},
     graph::{AnimationGraph, AnimationGraphAssetLoader, AnimationNodeIndex},
     transition::{advance_transitions, expire_completed_transitions, AnimationTransitions},
 };
@@ -88,6 +89,23 @@ impl VariableCurve {
     pub fn new(animation_curve: impl AnimationCurve) -> Self {
         Self(Box::new(animation_curve))
     }
+
+    /// Samples this curve at time `t`, if it animates a value of type `A`.
+    ///
+    /// This evaluates the curve on its own, without an [`AnimationPlayer`], which is useful to
+    /// bake or export an [`AnimationClip`]. Returns `None` if the curve animates another type of
+    /// value, such as the morph target weights animated by a [`WeightsCurve`].
+    ///
+    /// [`WeightsCurve`]: crate::animation_curves::WeightsCurve
+    pub fn sample_animatable<A: Animatable>(&self, t: f32) -> Option<A> {
+        let mut evaluator = self.0.create_evaluator();
+        self.0
+            .apply(&mut *evaluator, t, 1.0, AnimationNodeIndex::new(0))
+            .ok()?;
+        evaluator
+            .downcast_mut::<AnimatableCurveEvaluator<A>>()?
+            .pop_value()
+    }
 }
 
 /// A list of [`VariableCurve`]s and the [`AnimationTargetId`]s to which they
@@ -608,6 +626,13 @@ impl ActiveAnimation {
         self.completions
     }
 
+    /// Returns true if the animation completed at least once during the last tick.
+    ///
+    /// For repeating animations this is the tick in which the animation looped.
+    pub fn just_completed(&self) -> bool {
+        self.just_completed
+    }
+
     /// Returns true if the animation is playing in reverse.
     pub fn is_playback_reversed(&self) -> bool {
         self.speed < 0.0
@@ -636,6 +661,19 @@ impl ActiveAnimation {
         self.seek_time
     }
 
+    /// Returns the amount of clip time left until the animation reaches the end of the
+    /// current cycle, given the duration of the clip being played.
+    ///
+    /// When the playback is reversed, this is the time left until the start of the clip.
+    /// Divide by the absolute [`speed`](Self::speed) to get the remaining wall-clock time.
+    pub fn remaining(&self, clip_duration: f32) -> f32 {
+        if self.is_playback_reversed() {
+            self.seek_time
+        } else {
+            (clip_duration - self.seek_time).max(0.0)
+        }
+    }
+
     /// Seeks to a specific time in the animation.
     ///
     /// This will not trigger events between the current time and `seek_time`.
@@ -976,6 +1014,51 @@ fn trigger_untargeted_animation_events(
     }
 }
 
+/// Triggered on an [`AnimationPlayer`] entity when one of its animations finishes
+/// playing, according to its [`RepeatAnimation`] mode.
+#[derive(Event, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimationFinished {
+    /// The graph node of the animation that finished.
+    pub animation: AnimationNodeIndex,
+}
+
+/// Triggered on an [`AnimationPlayer`] entity when one of its repeating animations
+/// wraps around and starts a new cycle.
+#[derive(Event, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimationLooped {
+    /// The graph node of the animation that looped.
+    pub animation: AnimationNodeIndex,
+    /// The number of times the animation has completed so far.
+    pub completions: u32,
+}
+
+/// A system that triggers [`AnimationFinished`] and [`AnimationLooped`] for animations that
+/// completed a cycle during the last [`advance_animations`] tick.
+fn trigger_animation_completion_events(
+    mut commands: Commands,
+    players: Query<(Entity, &AnimationPlayer)>,
+) {
+    for (entity, player) in &players {
+        for (&animation, active_animation) in player.active_animations.iter() {
+            if !active_animation.just_completed {
+                continue;
+            }
+
+            if active_animation.is_finished() {
+                commands.trigger_targets(AnimationFinished { animation }, entity);
+            } else {
+                commands.trigger_targets(
+                    AnimationLooped {
+                        animation,
+                        completions: active_animation.completions,
+                    },
+                    entity,
+                );
+            }
+        }
+    }
+}
+
 /// A system that advances the time for all playing animations.
 pub fn advance_animations(
     time: Res<Time>,
@@ -1233,6 +1316,8 @@ impl Plugin for AnimationPlugin {
             .register_type::<AnimationTarget>()
             .register_type::<AnimationTransitions>()
             .register_type::<AnimationGraphHandle>()
+            .register_type::<AnimationFinished>()
+            .register_type::<AnimationLooped>()
             .register_type::<NodeIndex>()
             .register_type::<ThreadedAnimationGraphs>()
             .init_resource::<ThreadedAnimationGraphs>()
@@ -1252,6 +1337,7 @@ impl Plugin for AnimationPlugin {
                         .before(bevy_render::mesh::inherit_weights)
                         .ambiguous_with_all(),
                     trigger_untargeted_animation_events,
+                    trigger_animation_completion_events,
                     expire_completed_transitions,
                 )
                     .chain()
@@ -1610,6 +1696,26 @@ mod tests {
         assert_triggered_events_with(&active_animation, &clip, []);
     }
 
+    #[test]
+    fn test_remaining_and_just_completed() {
+        let mut active_animation = ActiveAnimation::default();
+        active_animation.repeat();
+
+        active_animation.update(0.25, 1.0);
+        assert_eq!(active_animation.remaining(1.0), 0.75);
+        assert!(!active_animation.just_completed());
+
+        active_animation.update(1.0, 1.0);
+        assert!(active_animation.just_completed());
+        assert_eq!(active_animation.completions(), 1);
+        assert_eq!(active_animation.remaining(1.0), 0.75);
+
+        active_animation.set_speed(-1.0);
+        active_animation.update(0.125, 1.0);
+        assert!(!active_animation.just_completed());
+        assert_eq!(active_animation.remaining(1.0), 0.125);
+    }
+
     #[test]
     fn test_events_triggers_looping() {
         let mut active_animation = ActiveAnimation {
@@ -1656,4 +1762,22 @@ mod tests {
         active_animation.update(clip.duration, clip.duration); // 0.3 : 0.0
         assert_triggered_events_with(&active_animation, &clip, [0.3, 0.2]);
     }
+
+    #[test]
+    fn sample_animatable_variable_curve() {
+        use bevy_math::Vec3;
+
+        #[derive(Clone, Component, Reflect)]
+        struct B {
+            x: f32,
+        }
+
+        let curve = VariableCurve::new(AnimatableCurve::new(
+            AnimatedField::new_unchecked("x", |b: &mut B| &mut b.x),
+            AnimatableKeyframeCurve::new([(0.0, 0.0), (1.0, 2.0)]).unwrap(),
+        ));
+        assert_eq!(curve.sample_animatable::<f32>(0.25), Some(0.5));
+        assert_eq!(curve.sample_animatable::<f32>(2.0), Some(2.0));
+        assert_eq!(curve.sample_animatable::<Vec3>(0.25), None);
+    }
 }
@@ -10,7 +10,7 @@ use alloc::{
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
-    event::{event_update_system, EventCursor},
+    event::{event_update_system, EventCursor, EventRetention},
     intern::Interned,
     prelude::*,
     result::{Error, SystemErrorContext},
@@ -156,6 +156,36 @@ impl App {
         self.sub_apps.update();
     }
 
+    /// Runs [`App::update`] the given number of times, without a [runner](Self::set_runner).
+    ///
+    /// This is meant for headless integration tests: run a fixed number of frames, then assert
+    /// on the state of the [`World`]. Combine it with a fixed time step (such as `bevy_time`'s
+    /// `TimeUpdateStrategy::ManualDuration`) and a fixed [`RngSeed`](crate::RngSeed) to make
+    /// the result reproducible.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Ticks(u32);
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Ticks>()
+    ///     .add_systems(Update, |mut ticks: ResMut<Ticks>| ticks.0 += 1);
+    ///
+    /// app.update_frames(10);
+    /// assert_eq!(app.world().resource::<Ticks>().0, 10);
+    /// ```
+    pub fn update_frames(&mut self, frames: u32) {
+        if self.plugins_state() == PluginsState::Ready {
+            self.finish();
+            self.cleanup();
+        }
+        for _ in 0..frames {
+            self.update();
+        }
+    }
+
     /// Runs the [`App`] by calling its [runner](Self::set_runner).
     ///
     /// This will (re)build the [`App`] first. For general usage, see the example on the item
@@ -317,6 +347,31 @@ impl App {
     /// This allows for running systems in a push-based fashion.
     /// Using a [`Schedule`] is still preferred for most cases
     /// due to its better performance and ability to run non-conflicting systems simultaneously.
+    ///
+    /// Plugins can expose named callbacks by storing the returned [`SystemId`] in a resource:
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, system::SystemId};
+    /// #[derive(Resource)]
+    /// struct OnGameOver(SystemId);
+    ///
+    /// fn show_score() {
+    ///     // ...
+    /// }
+    ///
+    /// fn check_health(mut commands: Commands, on_game_over: Res<OnGameOver>) {
+    ///     # let health = 0;
+    ///     if health == 0 {
+    ///         commands.run_system(on_game_over.0);
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// let on_game_over = app.register_system(show_score);
+    /// app.insert_resource(OnGameOver(on_game_over))
+    ///     .add_systems(Update, check_health);
+    /// ```
     pub fn register_system<I, O, M>(
         &mut self,
         system: impl IntoSystem<I, O, M> + 'static,
@@ -328,6 +383,50 @@ impl App {
         self.main_mut().register_system(system)
     }
 
+    /// Registers a system that runs once for every event of type `E`, with the event as input.
+    ///
+    /// The events are read in the given `schedule`, and the system runs through
+    /// [`Commands::run_system_with`](bevy_ecs::system::Commands::run_system_with), at the next
+    /// point where commands are applied. This saves writing a system that reads the events and
+    /// handles them one by one.
+    ///
+    /// The event type must be added with [`App::add_event`].
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Event, Clone)]
+    /// struct Damage(u32);
+    ///
+    /// #[derive(Resource)]
+    /// struct Health(u32);
+    ///
+    /// fn apply_damage(In(Damage(damage)): In<Damage>, mut health: ResMut<Health>) {
+    ///     health.0 = health.0.saturating_sub(damage);
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_event::<Damage>()
+    ///     .insert_resource(Health(100))
+    ///     .add_systems_on_event(Update, apply_damage);
+    ///
+    /// app.world_mut().send_event(Damage(25));
+    /// app.world_mut().send_event(Damage(10));
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Health>().0, 65);
+    /// ```
+    pub fn add_systems_on_event<E, M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        system: impl IntoSystem<In<E>, (), M> + 'static,
+    ) -> &mut Self
+    where
+        E: Event + Clone,
+    {
+        self.main_mut().add_systems_on_event(schedule, system);
+        self
+    }
+
     /// Configures a collection of system sets in the provided schedule, adding any sets that do not exist.
     #[track_caller]
     pub fn configure_sets(
@@ -364,6 +463,32 @@ impl App {
         self
     }
 
+    /// Initializes `T` event handling like [`add_event`](Self::add_event), but keeps the events
+    /// according to the given [`EventRetention`] instead of dropping them after two updates.
+    ///
+    /// If the event was already added, only its retention is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, event::EventRetention};
+    /// #
+    /// # #[derive(Event)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// // Keep the 64 most recent events for systems that only run once in a while.
+    /// app.add_event_with_retention::<MyEvent>(EventRetention::Capacity(64));
+    /// ```
+    pub fn add_event_with_retention<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.main_mut().add_event_with_retention::<T>(retention);
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -1442,7 +1567,7 @@ mod tests {
         change_detection::{DetectChanges, ResMut},
         component::Component,
         entity::Entity,
-        event::{Event, EventWriter, Events},
+        event::{Event, EventRetention, EventWriter, Events},
         query::With,
         removal_detection::RemovedComponents,
         resource::Resource,
@@ -1842,4 +1967,21 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn event_retention_of_unregistered_events() {
+        #[derive(Event, Clone)]
+        struct TestEvent;
+
+        let mut app = App::new();
+        // The resource exists, but the event isn't registered to be updated.
+        app.init_resource::<Events<TestEvent>>();
+        app.add_event_with_retention::<TestEvent>(EventRetention::Capacity(2));
+
+        for _ in 0..3 {
+            app.world_mut().send_event(TestEvent);
+        }
+        app.update();
+        assert_eq!(app.world().resource::<Events<TestEvent>>().len(), 2);
+    }
 }
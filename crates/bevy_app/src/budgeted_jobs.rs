@@ -0,0 +1,203 @@
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{
+    resource::Resource,
+    world::{Mut, World},
+};
+use bevy_platform_support::time::Instant;
+use core::time::Duration;
+
+/// The result of running one step of a job added to [`BudgetedJobs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JobStep {
+    /// The job has more work to do. Contains its progress, from `0.0` to `1.0`.
+    Continue(f32),
+    /// The job is finished and will be removed.
+    Done,
+}
+
+/// Identifies a job added to [`BudgetedJobs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JobId(u64);
+
+type JobFn = Box<dyn FnMut(&mut World) -> JobStep + Send + Sync>;
+
+struct BudgetedJob {
+    id: JobId,
+    step: JobFn,
+    progress: f32,
+}
+
+/// Long-running work split into small steps, run across frames within a
+/// per-frame time budget.
+///
+/// Each job is a closure that does a small amount of work every time it is
+/// called and reports its progress as a [`JobStep`]. Every frame,
+/// [`run_budgeted_jobs`] calls the jobs in turn until the [`budget`] is spent,
+/// so procedural generation or pathfinding can make steady progress without
+/// causing frame spikes. The closures get exclusive access to the [`World`] to
+/// apply their results, but can't access this resource while they run.
+///
+/// The [`TaskPoolPlugin`](crate::TaskPoolPlugin) inserts this resource and
+/// runs [`run_budgeted_jobs`] in [`Last`](crate::Last).
+///
+/// ```
+/// # use bevy_app::{BudgetedJobs, JobStep};
+/// # use bevy_ecs::prelude::*;
+/// fn start_generation(mut jobs: ResMut<BudgetedJobs>) {
+///     let mut row = 0;
+///     jobs.add(move |_world| {
+///         // generate a single row of the map
+///         row += 1;
+///         if row < 64 {
+///             JobStep::Continue(row as f32 / 64.0)
+///         } else {
+///             JobStep::Done
+///         }
+///     });
+/// }
+/// ```
+///
+/// [`budget`]: BudgetedJobs::budget
+#[derive(Resource)]
+pub struct BudgetedJobs {
+    jobs: Vec<BudgetedJob>,
+    next_id: u64,
+    budget: Duration,
+}
+
+impl BudgetedJobs {
+    /// The default time budget per frame.
+    ///
+    /// Equal to 2 milliseconds.
+    pub const DEFAULT_BUDGET: Duration = Duration::from_millis(2);
+
+    /// Creates an empty set of jobs with the given time budget per frame.
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            jobs: Vec::new(),
+            next_id: 0,
+            budget,
+        }
+    }
+
+    /// Returns the time the jobs may take each frame.
+    ///
+    /// At least one step is run every frame while there are jobs, even if the
+    /// step takes longer than the budget.
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Sets the time the jobs may take each frame.
+    pub fn set_budget(&mut self, budget: Duration) {
+        self.budget = budget;
+    }
+
+    /// Adds a job, which is called once per step until it returns [`JobStep::Done`].
+    pub fn add(
+        &mut self,
+        step: impl FnMut(&mut World) -> JobStep + Send + Sync + 'static,
+    ) -> JobId {
+        let id = JobId(self.next_id);
+        self.next_id += 1;
+        self.jobs.push(BudgetedJob {
+            id,
+            step: Box::new(step),
+            progress: 0.0,
+        });
+        id
+    }
+
+    /// Removes a job before it is finished. Returns `true` if the job was found.
+    pub fn cancel(&mut self, id: JobId) -> bool {
+        let len = self.jobs.len();
+        self.jobs.retain(|job| job.id != id);
+        self.jobs.len() != len
+    }
+
+    /// Returns the last progress reported by a job, or `None` if it is finished
+    /// or was canceled.
+    pub fn progress(&self, id: JobId) -> Option<f32> {
+        self.jobs
+            .iter()
+            .find(|job| job.id == id)
+            .map(|job| job.progress)
+    }
+
+    /// Returns the number of unfinished jobs.
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Returns `true` if there are no unfinished jobs.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+}
+
+impl Default for BudgetedJobs {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_BUDGET)
+    }
+}
+
+/// Runs the steps of the [`BudgetedJobs`] in turn until their budget for this
+/// frame is spent or all of them are finished.
+pub fn run_budgeted_jobs(world: &mut World) {
+    world.try_resource_scope(|world, mut jobs: Mut<BudgetedJobs>| {
+        let start = Instant::now();
+        let mut index = 0;
+        while !jobs.jobs.is_empty() {
+            if index >= jobs.jobs.len() {
+                index = 0;
+            }
+            let job = &mut jobs.jobs[index];
+            match (job.step)(world) {
+                JobStep::Continue(progress) => {
+                    job.progress = progress;
+                    index += 1;
+                }
+                JobStep::Done => {
+                    jobs.jobs.remove(index);
+                }
+            }
+            if start.elapsed() >= jobs.budget {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_jobs_to_completion() {
+        let mut world = World::new();
+        world.insert_resource(BudgetedJobs::new(Duration::ZERO));
+
+        let mut steps = 0;
+        let id = world.resource_mut::<BudgetedJobs>().add(move |_| {
+            steps += 1;
+            if steps < 4 {
+                JobStep::Continue(steps as f32 / 4.0)
+            } else {
+                JobStep::Done
+            }
+        });
+
+        // A zero budget still runs a single step every frame.
+        run_budgeted_jobs(&mut world);
+        assert_eq!(world.resource::<BudgetedJobs>().progress(id), Some(0.25));
+        run_budgeted_jobs(&mut world);
+        assert_eq!(world.resource::<BudgetedJobs>().progress(id), Some(0.5));
+
+        world
+            .resource_mut::<BudgetedJobs>()
+            .set_budget(Duration::from_secs(60));
+        run_budgeted_jobs(&mut world);
+        assert_eq!(world.resource::<BudgetedJobs>().progress(id), None);
+        assert!(world.resource::<BudgetedJobs>().is_empty());
+    }
+}
@@ -24,28 +24,40 @@ extern crate alloc;
 extern crate self as bevy_app;
 
 mod app;
+mod budgeted_jobs;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
 mod plugin_group;
+mod rng_seed;
 mod schedule_runner;
 mod sub_app;
 #[cfg(feature = "bevy_tasks")]
+mod task_component;
+#[cfg(feature = "bevy_tasks")]
 mod task_pool_plugin;
 #[cfg(all(any(unix, windows), feature = "std"))]
 mod terminal_ctrl_c_handler;
+#[cfg(feature = "bevy_tasks")]
+mod world_access;
 
 pub use app::*;
+pub use budgeted_jobs::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
 pub use plugin_group::*;
+pub use rng_seed::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
 #[cfg(feature = "bevy_tasks")]
+pub use task_component::*;
+#[cfg(feature = "bevy_tasks")]
 pub use task_pool_plugin::*;
 #[cfg(all(any(unix, windows), feature = "std"))]
 pub use terminal_ctrl_c_handler::*;
+#[cfg(feature = "bevy_tasks")]
+pub use world_access::*;
 
 /// The app prelude.
 ///
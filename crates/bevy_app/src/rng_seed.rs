@@ -0,0 +1,43 @@
+use bevy_ecs::resource::Resource;
+
+/// A seed that plugins and systems using random numbers should derive their generators from.
+///
+/// Bevy does not generate random numbers itself, so inserting this resource has no effect on
+/// its own. It is a shared hook: if every random number generator in an app is seeded from it,
+/// inserting a fixed seed makes runs reproducible, for example in integration tests on CI.
+///
+/// Use [`RngSeed::derive`] to give each generator its own stream, so that adding a new
+/// generator does not change the numbers drawn by the existing ones.
+///
+/// ```
+/// # use bevy_app::{App, RngSeed};
+/// # use bevy_ecs::prelude::*;
+/// const ENEMY_SPAWNS: u64 = 1;
+/// const LOOT_DROPS: u64 = 2;
+///
+/// fn setup_rng(seed: Res<RngSeed>) {
+///     let enemy_seed = seed.derive(ENEMY_SPAWNS);
+///     let loot_seed = seed.derive(LOOT_DROPS);
+///     // Seed your random number generators with these.
+/// #   assert_ne!(enemy_seed, loot_seed);
+/// }
+///
+/// App::new().insert_resource(RngSeed(42));
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RngSeed(pub u64);
+
+impl RngSeed {
+    /// Returns a seed for the given `stream`, deterministically derived from this seed.
+    ///
+    /// Different streams give unrelated seeds, even for adjacent stream values.
+    pub fn derive(&self, stream: u64) -> u64 {
+        // SplitMix64 finalizer, see <https://prng.di.unimi.it/splitmix64.c>.
+        let mut z = self
+            .0
+            .wrapping_add(stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
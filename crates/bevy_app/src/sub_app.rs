@@ -1,7 +1,7 @@
 use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
-    event::EventRegistry,
+    event::{EventRegistry, EventRetention},
     prelude::*,
     result::{DefaultSystemErrorHandler, SystemErrorContext},
     schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
@@ -232,6 +232,26 @@ impl SubApp {
         self.world.register_system(system)
     }
 
+    /// See [`App::add_systems_on_event`].
+    pub fn add_systems_on_event<E, M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        system: impl IntoSystem<In<E>, (), M> + 'static,
+    ) -> &mut Self
+    where
+        E: Event + Clone,
+    {
+        let id = self.register_system(system);
+        self.add_systems(
+            schedule,
+            move |mut events: EventReader<E>, mut commands: Commands| {
+                for event in events.read() {
+                    commands.run_system_with(id, event.clone());
+                }
+            },
+        )
+    }
+
     /// See [`App::configure_sets`].
     #[track_caller]
     pub fn configure_sets(
@@ -364,6 +384,20 @@ impl SubApp {
         self
     }
 
+    /// See [`App::add_event_with_retention`].
+    pub fn add_event_with_retention<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        if self.world.contains_resource::<Events<T>>() {
+            EventRegistry::set_retention::<T>(self.world_mut(), retention);
+        } else {
+            EventRegistry::register_event_with_retention::<T>(self.world_mut(), retention);
+        }
+
+        self
+    }
+
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
         self.run_as_app(|app| plugins.add_to_app(app));
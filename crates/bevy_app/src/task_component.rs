@@ -0,0 +1,251 @@
+// These types can't live in `bevy_tasks`: `bevy_ecs` depends on it to run systems in parallel,
+// so `bevy_tasks` can't depend on `bevy_ecs` for `Component` and `Resource`.
+
+use alloc::vec::Vec;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    resource::Resource,
+    system::{Commands, Query, ResMut},
+    world::CommandQueue,
+};
+use bevy_tasks::{futures::check_ready, Task};
+
+/// A [`Task`] stored on an entity, whose output is inserted on the same entity
+/// once it completes.
+///
+/// Add [`poll_tasks::<T>`](poll_tasks) to a schedule to check the tasks every
+/// time it runs. When a task is finished, its output is inserted as a
+/// component and the [`TaskComponent<T>`] is removed. Removing the
+/// [`TaskComponent<T>`] or despawning the entity beforehand cancels the task.
+///
+/// Use an [`EventTask`] instead to send the output as an event.
+///
+/// ```
+/// # use bevy_app::{poll_tasks, App, TaskComponent, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_tasks::AsyncComputeTaskPool;
+/// #[derive(Component)]
+/// struct Heightmap(Vec<f32>);
+///
+/// fn generate_heightmap(mut commands: Commands) {
+///     let task = AsyncComputeTaskPool::get().spawn(async { Heightmap(vec![0.0; 256]) });
+///     commands.spawn(TaskComponent(task));
+/// }
+///
+/// App::new()
+///     .add_systems(Update, (generate_heightmap, poll_tasks::<Heightmap>));
+/// ```
+#[derive(Component, Debug)]
+pub struct TaskComponent<T: Component>(pub Task<T>);
+
+/// Inserts the output of every finished [`TaskComponent<T>`] on its entity.
+///
+/// See [`TaskComponent`] for more details.
+pub fn poll_tasks<T: Component>(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut TaskComponent<T>)>,
+) {
+    for (entity, mut task) in &mut tasks {
+        if let Some(output) = check_ready(&mut task.0) {
+            commands
+                .entity(entity)
+                .remove::<TaskComponent<T>>()
+                .insert(output);
+        }
+    }
+}
+
+/// A [`Task`] stored on an entity, whose output is sent as an event once it completes.
+///
+/// Add [`send_task_events::<E>`](send_task_events) to a schedule to check the tasks every
+/// time it runs. When a task is finished, its output is sent as an event and the
+/// [`EventTask<E>`] is removed. Removing the [`EventTask<E>`] or despawning the entity
+/// beforehand cancels the task.
+///
+/// ```
+/// # use bevy_app::{send_task_events, App, EventTask, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_tasks::IoTaskPool;
+/// #[derive(Event)]
+/// struct SaveCompleted;
+///
+/// fn save_game(mut commands: Commands) {
+///     let task = IoTaskPool::get().spawn(async { SaveCompleted });
+///     commands.spawn(EventTask(task));
+/// }
+///
+/// App::new()
+///     .add_event::<SaveCompleted>()
+///     .add_systems(Update, (save_game, send_task_events::<SaveCompleted>));
+/// ```
+#[derive(Component, Debug)]
+pub struct EventTask<E: Event>(pub Task<E>);
+
+/// Sends the output of every finished [`EventTask<E>`] as an event.
+///
+/// See [`EventTask`] for more details.
+pub fn send_task_events<E: Event>(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut EventTask<E>)>,
+    mut events: EventWriter<E>,
+) {
+    for (entity, mut task) in &mut tasks {
+        if let Some(event) = check_ready(&mut task.0) {
+            events.write(event);
+            commands.entity(entity).remove::<EventTask<E>>();
+        }
+    }
+}
+
+/// [`Task`]s producing a [`CommandQueue`], which is applied to the world once the task completes.
+///
+/// The [`TaskPoolPlugin`](crate::TaskPoolPlugin) inserts this resource and runs
+/// [`apply_command_tasks`] in [`Last`](crate::Last).
+///
+/// ```
+/// # use bevy_app::CommandTasks;
+/// # use bevy_ecs::{prelude::*, world::CommandQueue};
+/// # use bevy_tasks::AsyncComputeTaskPool;
+/// #[derive(Component)]
+/// struct Chunk(Vec<u8>);
+///
+/// fn generate_chunk(mut command_tasks: ResMut<CommandTasks>) {
+///     let task = AsyncComputeTaskPool::get().spawn(async {
+///         let chunk = Chunk(vec![0; 1024]);
+///         let mut queue = CommandQueue::default();
+///         queue.push(move |world: &mut World| {
+///             world.spawn(chunk);
+///         });
+///         queue
+///     });
+///     command_tasks.push(task);
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct CommandTasks {
+    tasks: Vec<Task<CommandQueue>>,
+}
+
+impl CommandTasks {
+    /// Adds a task whose [`CommandQueue`] is applied once it completes.
+    pub fn push(&mut self, task: Task<CommandQueue>) {
+        self.tasks.push(task);
+    }
+
+    /// Returns the number of tasks that haven't completed yet.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if all tasks have completed.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+/// Applies the [`CommandQueue`] of every finished task in [`CommandTasks`].
+///
+/// The commands are applied in the order the tasks were pushed.
+pub fn apply_command_tasks(mut commands: Commands, mut tasks: ResMut<CommandTasks>) {
+    if tasks.is_empty() {
+        return;
+    }
+    tasks.tasks.retain_mut(|task| match check_ready(task) {
+        Some(mut queue) => {
+            commands.append(&mut queue);
+            false
+        }
+        None => true,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{event::Events, schedule::Schedule, world::World};
+    use bevy_tasks::TaskPool;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Value(u32);
+
+    #[test]
+    fn inserts_finished_task_output() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(poll_tasks::<Value>);
+
+        let pool = TaskPool::new();
+        let ready = world
+            .spawn(TaskComponent(pool.spawn_local(async { Value(3) })))
+            .id();
+        let pending = world
+            .spawn(TaskComponent(
+                pool.spawn_local(core::future::pending::<Value>()),
+            ))
+            .id();
+        pool.with_local_executor(|executor| while executor.try_tick() {});
+
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Value>(ready), Some(&Value(3)));
+        assert!(world.get::<TaskComponent<Value>>(ready).is_none());
+        assert!(world.get::<Value>(pending).is_none());
+        assert!(world.get::<TaskComponent<Value>>(pending).is_some());
+    }
+
+    #[derive(Event, Debug, PartialEq)]
+    struct Finished(u32);
+
+    #[test]
+    fn sends_finished_task_events() {
+        let mut world = World::new();
+        world.init_resource::<Events<Finished>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(send_task_events::<Finished>);
+
+        let pool = TaskPool::new();
+        let ready = world
+            .spawn(EventTask(pool.spawn_local(async { Finished(5) })))
+            .id();
+        let pending = world
+            .spawn(EventTask(
+                pool.spawn_local(core::future::pending::<Finished>()),
+            ))
+            .id();
+        pool.with_local_executor(|executor| while executor.try_tick() {});
+
+        schedule.run(&mut world);
+
+        let events: Vec<_> = world.resource_mut::<Events<Finished>>().drain().collect();
+        assert_eq!(events, [Finished(5)]);
+        assert!(world.get::<EventTask<Finished>>(ready).is_none());
+        assert!(world.get::<EventTask<Finished>>(pending).is_some());
+    }
+
+    #[test]
+    fn applies_finished_command_tasks() {
+        let mut world = World::new();
+        world.init_resource::<CommandTasks>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_command_tasks);
+
+        let pool = TaskPool::new();
+        let mut tasks = world.resource_mut::<CommandTasks>();
+        tasks.push(pool.spawn_local(async {
+            let mut queue = CommandQueue::default();
+            queue.push(|world: &mut World| {
+                world.spawn(Value(7));
+            });
+            queue
+        }));
+        tasks.push(pool.spawn_local(core::future::pending::<CommandQueue>()));
+        pool.with_local_executor(|executor| while executor.try_tick() {});
+
+        schedule.run(&mut world);
+
+        assert_eq!(world.resource::<CommandTasks>().len(), 1);
+        assert_eq!(world.query::<&Value>().single(&world).unwrap(), &Value(7));
+    }
+}
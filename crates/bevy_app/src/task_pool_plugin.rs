@@ -1,13 +1,16 @@
-use crate::{App, Plugin};
+use crate::{
+    apply_command_tasks, apply_world_access, run_budgeted_jobs, App, BudgetedJobs, CommandTasks,
+    Last, Plugin, WorldAccess,
+};
 
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use bevy_platform_support::sync::Arc;
 use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
 use core::{fmt::Debug, marker::PhantomData};
 use log::trace;
 
 #[cfg(not(target_arch = "wasm32"))]
-use {crate::Last, bevy_ecs::prelude::NonSend};
+use bevy_ecs::prelude::NonSend;
 
 #[cfg(not(target_arch = "wasm32"))]
 use bevy_tasks::tick_global_task_pools_on_main_thread;
@@ -20,12 +23,20 @@ pub struct TaskPoolPlugin {
 }
 
 impl Plugin for TaskPoolPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, app: &mut App) {
         // Setup the default bevy task pools
         self.task_pool_options.create_default_pools();
 
+        app.init_resource::<WorldAccess>()
+            .init_resource::<BudgetedJobs>()
+            .init_resource::<CommandTasks>()
+            .add_systems(
+                Last,
+                (apply_world_access, run_budgeted_jobs, apply_command_tasks),
+            );
+
         #[cfg(not(target_arch = "wasm32"))]
-        _app.add_systems(Last, tick_global_task_pools);
+        app.add_systems(Last, tick_global_task_pools);
     }
 }
 /// A dummy type that is [`!Send`](Send), to force systems to run on the main thread.
@@ -42,6 +53,13 @@ fn tick_global_task_pools(_main_thread_marker: Option<NonSend<NonSendMarker>>) {
 
 /// Defines a simple way to determine how many threads to use given the number of remaining cores
 /// and number of total cores
+///
+/// Besides a struct literal, it can be created with [`TaskPoolThreadAssignmentPolicy::new`] and its
+/// builder methods.
+///
+/// The task pools are created once when the app starts and can't be resized afterwards, and their
+/// threads are not pinned to specific cores. To set the core affinity of a thread, call a crate
+/// such as `core_affinity` from [`on_thread_spawn`](Self::on_thread_spawn).
 #[derive(Clone)]
 pub struct TaskPoolThreadAssignmentPolicy {
     /// Force using at least this many threads
@@ -51,6 +69,11 @@ pub struct TaskPoolThreadAssignmentPolicy {
     /// Target using this percentage of total cores, clamped by `min_threads` and `max_threads`. It is
     /// permitted to use 1.0 to try to use all remaining threads
     pub percent: f32,
+    /// Prefix used to name the threads of this pool, followed by the thread index.
+    ///
+    /// Defaults to the name of the pool, e.g. `"Compute Task Pool (2)"`, if `None`.
+    /// This configuration will be ignored under wasm platform.
+    pub thread_name: Option<String>,
     /// Callback that is invoked once for every created thread as it starts.
     /// This configuration will be ignored under wasm platform.
     pub on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
@@ -65,11 +88,43 @@ impl Debug for TaskPoolThreadAssignmentPolicy {
             .field("min_threads", &self.min_threads)
             .field("max_threads", &self.max_threads)
             .field("percent", &self.percent)
+            .field("thread_name", &self.thread_name)
             .finish()
     }
 }
 
 impl TaskPoolThreadAssignmentPolicy {
+    /// Creates a policy targeting `percent` of the total cores, clamped by `min_threads` and
+    /// `max_threads`.
+    pub fn new(min_threads: usize, max_threads: usize, percent: f32) -> Self {
+        Self {
+            min_threads,
+            max_threads,
+            percent,
+            thread_name: None,
+            on_thread_spawn: None,
+            on_thread_destroy: None,
+        }
+    }
+
+    /// Sets the prefix used to name the threads of this pool.
+    pub fn with_thread_name(mut self, thread_name: impl Into<String>) -> Self {
+        self.thread_name = Some(thread_name.into());
+        self
+    }
+
+    /// Sets the callback invoked once for every created thread as it starts.
+    pub fn with_on_thread_spawn(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_thread_spawn = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets the callback invoked once for every created thread as it terminates.
+    pub fn with_on_thread_destroy(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_thread_destroy = Some(Arc::new(f));
+        self
+    }
+
     /// Determine the number of threads to use for this task pool
     fn get_number_of_threads(&self, remaining_threads: usize, total_threads: usize) -> usize {
         assert!(self.percent >= 0.0);
@@ -119,31 +174,14 @@ impl Default for TaskPoolOptions {
             max_total_threads: usize::MAX,
 
             // Use 25% of cores for IO, at least 1, no more than 4
-            io: TaskPoolThreadAssignmentPolicy {
-                min_threads: 1,
-                max_threads: 4,
-                percent: 0.25,
-                on_thread_spawn: None,
-                on_thread_destroy: None,
-            },
+            io: TaskPoolThreadAssignmentPolicy::new(1, 4, 0.25),
 
             // Use 25% of cores for async compute, at least 1, no more than 4
-            async_compute: TaskPoolThreadAssignmentPolicy {
-                min_threads: 1,
-                max_threads: 4,
-                percent: 0.25,
-                on_thread_spawn: None,
-                on_thread_destroy: None,
-            },
+            async_compute: TaskPoolThreadAssignmentPolicy::new(1, 4, 0.25),
 
             // Use all remaining cores for compute (at least 1)
-            compute: TaskPoolThreadAssignmentPolicy {
-                min_threads: 1,
-                max_threads: usize::MAX,
-                percent: 1.0, // This 1.0 here means "whatever is left over"
-                on_thread_spawn: None,
-                on_thread_destroy: None,
-            },
+            // This 1.0 here means "whatever is left over"
+            compute: TaskPoolThreadAssignmentPolicy::new(1, usize::MAX, 1.0),
         }
     }
 }
@@ -179,7 +217,12 @@ impl TaskPoolOptions {
                 #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
                 let mut builder = TaskPoolBuilder::default()
                     .num_threads(io_threads)
-                    .thread_name("IO Task Pool".to_string());
+                    .thread_name(
+                        self.io
+                            .thread_name
+                            .clone()
+                            .unwrap_or_else(|| "IO Task Pool".to_string()),
+                    );
 
                 #[cfg(not(target_arch = "wasm32"))]
                 {
@@ -208,7 +251,12 @@ impl TaskPoolOptions {
                 #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
                 let mut builder = TaskPoolBuilder::default()
                     .num_threads(async_compute_threads)
-                    .thread_name("Async Compute Task Pool".to_string());
+                    .thread_name(
+                        self.async_compute
+                            .thread_name
+                            .clone()
+                            .unwrap_or_else(|| "Async Compute Task Pool".to_string()),
+                    );
 
                 #[cfg(not(target_arch = "wasm32"))]
                 {
@@ -237,7 +285,12 @@ impl TaskPoolOptions {
                 #[cfg_attr(target_arch = "wasm32", expect(unused_mut))]
                 let mut builder = TaskPoolBuilder::default()
                     .num_threads(compute_threads)
-                    .thread_name("Compute Task Pool".to_string());
+                    .thread_name(
+                        self.compute
+                            .thread_name
+                            .clone()
+                            .unwrap_or_else(|| "Compute Task Pool".to_string()),
+                    );
 
                 #[cfg(not(target_arch = "wasm32"))]
                 {
@@ -0,0 +1,205 @@
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{
+    resource::Resource,
+    system::{RegisteredSystemError, SystemId, SystemInput},
+    world::World,
+};
+use bevy_platform_support::sync::{Arc, Mutex, PoisonError};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+type WorldCallback = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A handle that lets async tasks request exclusive access to the [`World`].
+///
+/// Tasks running on one of the [task pools](bevy_tasks) can't borrow the world
+/// directly. Instead, they clone this resource into their future and call
+/// [`with_world`](WorldAccess::with_world), which queues a closure to be run
+/// by [`apply_world_access`] at the next sync point and returns a future
+/// resolving to the closure's result. This lets long-running tasks apply their
+/// results without a hand-written channel and polling system.
+///
+/// Tasks can also request that a registered system runs, with
+/// [`run_system`](WorldAccess::run_system).
+///
+/// The [`TaskPoolPlugin`](crate::TaskPoolPlugin) inserts this resource and
+/// runs [`apply_world_access`] in [`Last`](crate::Last).
+///
+/// ```
+/// # use bevy_app::WorldAccess;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_tasks::AsyncComputeTaskPool;
+/// #[derive(Component)]
+/// struct Chunk(Vec<u8>);
+///
+/// fn generate_chunk(world_access: Res<WorldAccess>) {
+///     let world_access = world_access.clone();
+///     AsyncComputeTaskPool::get()
+///         .spawn(async move {
+///             let chunk = Chunk(vec![0; 1024]);
+///             let entity = world_access
+///                 .with_world(move |world| world.spawn(chunk).id())
+///                 .await;
+///             // ...continue working with `entity`
+///         })
+///         .detach();
+/// }
+/// ```
+#[derive(Resource, Clone, Default)]
+pub struct WorldAccess {
+    queue: Arc<Mutex<Vec<WorldCallback>>>,
+}
+
+impl WorldAccess {
+    /// Queues `f` to be run with exclusive access to the [`World`] at the next
+    /// sync point, returning a future that resolves to its result.
+    ///
+    /// The future never resolves if the closure is never run, for example
+    /// because the app exits first.
+    pub fn with_world<R, F>(&self, f: F) -> WithWorld<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut World) -> R + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(WithWorldState {
+            result: None,
+            waker: None,
+        }));
+        let task_state = state.clone();
+        self.queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Box::new(move |world: &mut World| {
+                let result = f(world);
+                let mut state = task_state.lock().unwrap_or_else(PoisonError::into_inner);
+                state.result = Some(result);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            }));
+        WithWorld { state }
+    }
+
+    /// Queues the registered system with the given [`SystemId`] to be run at the next sync
+    /// point, returning a future that resolves to its result.
+    ///
+    /// See [`World::run_system`].
+    pub fn run_system<O>(
+        &self,
+        id: SystemId<(), O>,
+    ) -> WithWorld<Result<O, RegisteredSystemError<(), O>>>
+    where
+        O: Send + 'static,
+    {
+        self.with_world(move |world| world.run_system(id))
+    }
+
+    /// Queues the registered system with the given [`SystemId`] to be run with the given input
+    /// at the next sync point, returning a future that resolves to its result.
+    ///
+    /// See [`World::run_system_with`].
+    pub fn run_system_with<I, O>(
+        &self,
+        id: SystemId<I, O>,
+        input: I::Inner<'static>,
+    ) -> WithWorld<Result<O, RegisteredSystemError<I, O>>>
+    where
+        I: SystemInput<Inner<'static>: Send> + Send + 'static,
+        O: Send + 'static,
+    {
+        self.with_world(move |world| world.run_system_with(id, input))
+    }
+
+    /// Returns the number of closures waiting to be run.
+    pub fn pending(&self) -> usize {
+        self.queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+struct WithWorldState<R> {
+    result: Option<R>,
+    waker: Option<Waker>,
+}
+
+/// A future returned by [`WorldAccess::with_world`], resolving to the result of
+/// the queued closure once it has been run.
+pub struct WithWorld<R> {
+    state: Arc<Mutex<WithWorldState<R>>>,
+}
+
+impl<R> Future for WithWorld<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Runs all closures queued through [`WorldAccess::with_world`].
+///
+/// Closures queued while this system runs, including by the closures
+/// themselves, are run the next time it runs.
+pub fn apply_world_access(world: &mut World) {
+    let Some(world_access) = world.get_resource::<WorldAccess>() else {
+        return;
+    };
+    let callbacks = core::mem::take(
+        &mut *world_access
+            .queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+    for callback in callbacks {
+        callback(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::{block_on, poll_once};
+
+    #[test]
+    fn runs_queued_closures() {
+        let mut world = World::new();
+        let world_access = WorldAccess::default();
+        world.insert_resource(world_access.clone());
+
+        let mut request = world_access.with_world(|world| world.spawn_empty().id());
+        assert_eq!(world_access.pending(), 1);
+        assert!(block_on(poll_once(&mut request)).is_none());
+
+        apply_world_access(&mut world);
+        assert_eq!(world_access.pending(), 0);
+
+        let entity = block_on(poll_once(&mut request)).unwrap();
+        assert!(world.get_entity(entity).is_ok());
+    }
+
+    #[test]
+    fn runs_registered_systems() {
+        use bevy_ecs::system::In;
+
+        let mut world = World::new();
+        let world_access = WorldAccess::default();
+        world.insert_resource(world_access.clone());
+        let id = world.register_system(|In(value): In<u32>| value * 2);
+
+        let mut request = world_access.run_system_with(id, 21);
+        apply_world_access(&mut world);
+        assert!(matches!(block_on(poll_once(&mut request)), Some(Ok(42))));
+    }
+}
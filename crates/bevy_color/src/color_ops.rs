@@ -1,4 +1,8 @@
-use bevy_math::{ops, Vec3, Vec4};
+use crate::{Color, Hsla, Hsva, Hwba, Laba, Lcha, LinearRgba, Oklaba, Oklcha, Srgba, Xyza};
+use bevy_math::{
+    curve::{Curve, Ease, FunctionCurve, Interval},
+    ops, StableInterpolate, Vec3, Vec4,
+};
 
 /// Methods for changing the luminance of a color. Note that these methods are not
 /// guaranteed to produce consistent results across color spaces,
@@ -135,12 +139,49 @@ pub(crate) fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
     ops::rem_euclid(a + diff * t, 360.)
 }
 
+/// Implements [`StableInterpolate`] in terms of [`Mix`], so that colors can be used with
+/// [`StableInterpolate::smooth_nudge`] and the other interpolation helpers in `bevy_math`.
+macro_rules! impl_stable_interpolate_with_mix {
+    ($($ty:ty),*) => {
+        $(
+            impl StableInterpolate for $ty {
+                #[inline]
+                fn interpolate_stable(&self, other: &Self, t: f32) -> Self {
+                    self.mix(other, t)
+                }
+            }
+        )*
+    };
+}
+
+impl_stable_interpolate_with_mix!(
+    Color, Hsla, Hsva, Hwba, Laba, Lcha, LinearRgba, Oklaba, Oklcha, Srgba, Xyza
+);
+
+/// Implements [`Ease`] in terms of [`Mix`] for the color spaces that are not a
+/// [`VectorSpace`](bevy_math::VectorSpace), so that hues are eased along the shortest path
+/// around the color wheel.
+macro_rules! impl_ease_with_mix {
+    ($($ty:ty),*) => {
+        $(
+            impl Ease for $ty {
+                fn interpolating_curve_unbounded(start: Self, end: Self) -> impl Curve<Self> {
+                    FunctionCurve::new(Interval::EVERYWHERE, move |t| start.mix(&end, t))
+                }
+            }
+        )*
+    };
+}
+
+impl_ease_with_mix!(Color, Hsla, Hsva, Hwba, Lcha, Oklcha);
+
 #[cfg(test)]
 mod tests {
     use core::fmt::Debug;
 
     use super::*;
-    use crate::{testing::assert_approx_eq, Hsla};
+    use crate::testing::assert_approx_eq;
+    use bevy_math::curve::{EaseFunction, EasingCurve};
 
     #[test]
     fn test_rotate_hue() {
@@ -193,4 +234,15 @@ mod tests {
         verify_gray::<crate::Oklcha>();
         verify_gray::<crate::Xyza>();
     }
+
+    #[test]
+    fn test_interpolate_hue_colors() {
+        let red = Hsla::hsl(350.0, 1.0, 0.5);
+        let orange = Hsla::hsl(30.0, 1.0, 0.5);
+        assert_approx_eq!(red.interpolate_stable(&orange, 0.5).hue, 10.0, 0.001);
+
+        let curve = EasingCurve::new(red, orange, EaseFunction::Linear);
+        assert_approx_eq!(curve.sample_clamped(0.25).hue, 0.0, 0.001);
+        assert_eq!(curve.sample_clamped(1.0), orange);
+    }
 }
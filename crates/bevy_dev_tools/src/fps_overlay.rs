@@ -3,23 +3,25 @@
 use bevy_app::{Plugin, Startup, Update};
 use bevy_asset::Handle;
 use bevy_color::Color;
-use bevy_diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy_diagnostic::{DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
 use bevy_ecs::{
     change_detection::DetectChangesMut,
     component::Component,
     entity::Entity,
+    hierarchy::Children,
     prelude::Local,
     query::With,
     resource::Resource,
     schedule::{common_conditions::resource_changed, IntoSystemConfigs},
-    system::{Commands, Query, Res},
+    system::{Commands, Query, Res, ResMut},
 };
+use bevy_input::{keyboard::KeyCode, ButtonInput};
 use bevy_render::view::Visibility;
 use bevy_text::{Font, TextColor, TextFont, TextSpan};
 use bevy_time::Time;
 use bevy_ui::{
     widget::{Text, TextUiWriter},
-    GlobalZIndex, Node, PositionType,
+    AlignItems, BackgroundColor, Display, FlexDirection, GlobalZIndex, Node, PositionType, Val,
 };
 use core::time::Duration;
 
@@ -28,9 +30,17 @@ use core::time::Duration;
 /// We use a number slightly under `i32::MAX` so you can render on top of it if you really need to.
 pub const FPS_OVERLAY_ZINDEX: i32 = i32::MAX - 32;
 
+/// The number of frames shown in the frame time graph of the FPS overlay.
+pub const FRAME_TIME_GRAPH_LEN: usize = 60;
+
 /// A plugin that adds an FPS overlay to the Bevy application.
 ///
-/// This plugin will add the [`FrameTimeDiagnosticsPlugin`] if it wasn't added before.
+/// Besides the FPS, the overlay can show a graph of recent frame times and the entity count,
+/// and be toggled with a key, see [`FpsOverlayConfig`]. Draw calls aren't shown, as the renderer
+/// doesn't record them as a diagnostic.
+///
+/// This plugin will add the [`FrameTimeDiagnosticsPlugin`] and the
+/// [`EntityCountDiagnosticsPlugin`] if they weren't added before.
 ///
 /// Note: It is recommended to use native overlay of rendering statistics when possible for lower overhead and more accurate results.
 /// The correct way to do this will vary by platform:
@@ -47,14 +57,20 @@ impl Plugin for FpsOverlayPlugin {
         if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
             app.add_plugins(FrameTimeDiagnosticsPlugin::default());
         }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
         app.insert_resource(self.config.clone())
             .add_systems(Startup, setup)
             .add_systems(
                 Update,
                 (
-                    (customize_text, toggle_display).run_if(resource_changed::<FpsOverlayConfig>),
-                    update_text,
-                ),
+                    toggle_enabled,
+                    (customize_overlay, toggle_display)
+                        .run_if(resource_changed::<FpsOverlayConfig>),
+                    (update_text, update_graph),
+                )
+                    .chain(),
             );
     }
 }
@@ -68,10 +84,18 @@ pub struct FpsOverlayConfig {
     pub text_color: Color,
     /// Displays the FPS overlay if true.
     pub enabled: bool,
+    /// The key that toggles [`enabled`](Self::enabled), if any.
+    ///
+    /// Defaults to `None`.
+    pub toggle_key: Option<KeyCode>,
     /// The period after which the FPS overlay re-renders.
     ///
     /// Defaults to once every 100 ms.
     pub refresh_interval: Duration,
+    /// Displays the number of entities below the FPS if true.
+    pub show_entity_count: bool,
+    /// Configuration of the frame time graph.
+    pub frame_time_graph_config: FrameTimeGraphConfig,
 }
 
 impl Default for FpsOverlayConfig {
@@ -84,36 +108,124 @@ impl Default for FpsOverlayConfig {
             },
             text_color: Color::WHITE,
             enabled: true,
+            toggle_key: None,
             refresh_interval: Duration::from_millis(100),
+            show_entity_count: false,
+            frame_time_graph_config: FrameTimeGraphConfig::default(),
         }
     }
 }
 
+/// Configuration options for the frame time graph of the FPS overlay.
+///
+/// The graph shows one bar per frame for the last [`FRAME_TIME_GRAPH_LEN`] frames.
+#[derive(Clone, Copy)]
+pub struct FrameTimeGraphConfig {
+    /// Displays the frame time graph if true.
+    pub enabled: bool,
+    /// Color of the bars in the graph.
+    pub color: Color,
+    /// The frame rate at which a bar of the graph is full.
+    ///
+    /// Defaults to `30.0`.
+    pub min_fps: f64,
+}
+
+impl Default for FrameTimeGraphConfig {
+    fn default() -> Self {
+        FrameTimeGraphConfig {
+            enabled: false,
+            color: Color::srgb(0.2, 0.8, 0.3),
+            min_fps: 30.0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct FpsOverlay;
+
 #[derive(Component)]
 struct FpsText;
 
+#[derive(Component)]
+struct FrameTimeGraph;
+
+/// A bar in the frame time graph. The bars are the children of the [`FrameTimeGraph`], ordered
+/// from the oldest frame to the most recent one.
+#[derive(Component)]
+struct FrameTimeBar;
+
+// Indices of the text spans, see `setup`.
+const FPS_SPAN: usize = 1;
+const ENTITY_COUNT_LABEL_SPAN: usize = 2;
+const ENTITY_COUNT_SPAN: usize = 3;
+
 fn setup(mut commands: Commands, overlay_config: Res<FpsOverlayConfig>) {
+    let text_font = overlay_config.text_config.clone();
     commands
         .spawn((
             Node {
                 // We need to make sure the overlay doesn't affect the position of other UI nodes
                 position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
                 ..Default::default()
             },
             // Render overlay on top of everything
             GlobalZIndex(FPS_OVERLAY_ZINDEX),
+            FpsOverlay,
         ))
         .with_children(|p| {
             p.spawn((
                 Text::new("FPS: "),
-                overlay_config.text_config.clone(),
+                text_font.clone(),
                 TextColor(overlay_config.text_color),
                 FpsText,
             ))
-            .with_child((TextSpan::default(), overlay_config.text_config.clone()));
+            .with_children(|p| {
+                p.spawn((TextSpan::default(), text_font.clone()));
+                p.spawn((TextSpan::default(), text_font.clone()));
+                p.spawn((TextSpan::default(), text_font.clone()));
+            });
+            p.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::FlexEnd,
+                    height: Val::Px(40.0),
+                    ..Default::default()
+                },
+                FrameTimeGraph,
+            ))
+            .with_children(|p| {
+                for _ in 0..FRAME_TIME_GRAPH_LEN {
+                    p.spawn((
+                        Node {
+                            width: Val::Px(3.0),
+                            height: Val::Percent(0.0),
+                            ..Default::default()
+                        },
+                        BackgroundColor(overlay_config.frame_time_graph_config.color),
+                        FrameTimeBar,
+                    ));
+                }
+            });
         });
 }
 
+fn toggle_enabled(
+    input: Option<Res<ButtonInput<KeyCode>>>,
+    mut overlay_config: ResMut<FpsOverlayConfig>,
+) {
+    let Some(input) = input else {
+        return;
+    };
+    if overlay_config
+        .toggle_key
+        .is_some_and(|key| input.just_pressed(key))
+    {
+        overlay_config.enabled = !overlay_config.enabled;
+    }
+}
+
 fn update_text(
     diagnostic: Res<DiagnosticsStore>,
     query: Query<Entity, With<FpsText>>,
@@ -128,29 +240,90 @@ fn update_text(
         for entity in &query {
             if let Some(fps) = diagnostic.get(&FrameTimeDiagnosticsPlugin::FPS) {
                 if let Some(value) = fps.smoothed() {
-                    *writer.text(entity, 1) = format!("{value:.2}");
+                    *writer.text(entity, FPS_SPAN) = format!("{value:.2}");
+                }
+            }
+            if config.show_entity_count {
+                if let Some(entity_count) = diagnostic
+                    .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+                    .and_then(|entity_count| entity_count.value())
+                {
+                    *writer.text(entity, ENTITY_COUNT_SPAN) = format!("{entity_count}");
                 }
             }
         }
     }
 }
 
-fn customize_text(
+fn update_graph(
+    diagnostic: Res<DiagnosticsStore>,
+    graph_query: Query<&Children, With<FrameTimeGraph>>,
+    mut bar_query: Query<&mut Node, With<FrameTimeBar>>,
+    config: Res<FpsOverlayConfig>,
+) {
+    let graph_config = config.frame_time_graph_config;
+    if !config.enabled || !graph_config.enabled {
+        return;
+    }
+    let Some(frame_times) = diagnostic.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME) else {
+        return;
+    };
+
+    // Right-align the most recent frame times in the graph.
+    let skipped = frame_times
+        .history_len()
+        .saturating_sub(FRAME_TIME_GRAPH_LEN);
+    let offset = FRAME_TIME_GRAPH_LEN.saturating_sub(frame_times.history_len());
+    let max_frame_time = 1000.0 / graph_config.min_fps;
+    for bars in &graph_query {
+        let values = core::iter::repeat_n(&0.0, offset).chain(frame_times.values().skip(skipped));
+        for (&bar, &frame_time) in bars.iter().zip(values) {
+            if let Ok(mut node) = bar_query.get_mut(bar) {
+                let fraction = (frame_time / max_frame_time).clamp(0.0, 1.0);
+                node.height = Val::Percent(fraction as f32 * 100.0);
+            }
+        }
+    }
+}
+
+fn customize_overlay(
     overlay_config: Res<FpsOverlayConfig>,
-    query: Query<Entity, With<FpsText>>,
+    text_query: Query<Entity, With<FpsText>>,
+    mut graph_query: Query<&mut Node, With<FrameTimeGraph>>,
+    mut bar_query: Query<&mut BackgroundColor, With<FrameTimeBar>>,
     mut writer: TextUiWriter,
 ) {
-    for entity in &query {
+    for entity in &text_query {
         writer.for_each_font(entity, |mut font| {
             *font = overlay_config.text_config.clone();
         });
         writer.for_each_color(entity, |mut color| color.0 = overlay_config.text_color);
+        if overlay_config.show_entity_count {
+            *writer.text(entity, ENTITY_COUNT_LABEL_SPAN) = "\nEntities: ".into();
+        } else {
+            writer.text(entity, ENTITY_COUNT_LABEL_SPAN).clear();
+            writer.text(entity, ENTITY_COUNT_SPAN).clear();
+        }
+    }
+
+    let graph_config = overlay_config.frame_time_graph_config;
+    for mut node in &mut graph_query {
+        let display = match graph_config.enabled {
+            true => Display::Flex,
+            false => Display::None,
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    for mut color in &mut bar_query {
+        color.set_if_neq(BackgroundColor(graph_config.color));
     }
 }
 
 fn toggle_display(
     overlay_config: Res<FpsOverlayConfig>,
-    mut query: Query<&mut Visibility, With<FpsText>>,
+    mut query: Query<&mut Visibility, With<FpsOverlay>>,
 ) {
     for mut visibility in &mut query {
         visibility.set_if_neq(match overlay_config.enabled {
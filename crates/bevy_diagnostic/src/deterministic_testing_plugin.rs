@@ -0,0 +1,61 @@
+use bevy_app::prelude::*;
+use bevy_app::RngSeed;
+use bevy_time::TimeUpdateStrategy;
+use core::time::Duration;
+
+/// Makes an app reproducible across runs, for gameplay integration tests running on CI.
+///
+/// This plugin:
+/// - advances time by exactly [`frame_time`](Self::frame_time) every update, using
+///   [`TimeUpdateStrategy::ManualDuration`], regardless of how long the update actually took.
+/// - inserts [`seed`](Self::seed) as the [`RngSeed`] that random number generators should be
+///   seeded from.
+///
+/// Use [`App::update_frames`] to run a number of frames headlessly, then assert on the world.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_diagnostic::DeterministicTestingPlugin;
+/// # use bevy_time::{Time, TimePlugin};
+/// # use core::time::Duration;
+/// let mut app = App::new();
+/// app.add_plugins((
+///     TimePlugin,
+///     DeterministicTestingPlugin {
+///         frame_time: Duration::from_millis(10),
+///         seed: 1234,
+///     },
+/// ));
+///
+/// app.update_frames(11);
+/// // Time starts counting from the first update.
+/// let elapsed = app.world().resource::<Time>().elapsed();
+/// assert_eq!(elapsed, Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeterministicTestingPlugin {
+    /// The amount of time each update advances the clock by.
+    ///
+    /// Defaults to 1/60th of a second.
+    pub frame_time: Duration,
+    /// The seed inserted as the [`RngSeed`] resource.
+    ///
+    /// Defaults to `0`.
+    pub seed: u64,
+}
+
+impl Default for DeterministicTestingPlugin {
+    fn default() -> Self {
+        Self {
+            frame_time: Duration::from_secs_f64(1.0 / 60.0),
+            seed: 0,
+        }
+    }
+}
+
+impl Plugin for DeterministicTestingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(self.frame_time))
+            .insert_resource(RngSeed(self.seed));
+    }
+}
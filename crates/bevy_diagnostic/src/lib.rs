@@ -16,20 +16,30 @@ extern crate std;
 
 extern crate alloc;
 
+mod deterministic_testing_plugin;
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+mod registered_system_diagnostics_plugin;
+mod schedule_time_diagnostics_plugin;
+mod storage_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
 
+pub use deterministic_testing_plugin::DeterministicTestingPlugin;
 pub use diagnostic::*;
 
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_count_diagnostics_plugin::{update_frame_count, FrameCount, FrameCountPlugin};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+pub use registered_system_diagnostics_plugin::RegisteredSystemDiagnosticsPlugin;
+pub use schedule_time_diagnostics_plugin::{
+    MeasuredSchedules, ScheduleTimeDiagnosticsPlugin, SystemTimeReport, SystemTimeStats,
+};
+pub use storage_diagnostics_plugin::StorageDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
 
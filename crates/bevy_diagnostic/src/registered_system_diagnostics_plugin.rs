@@ -0,0 +1,67 @@
+use alloc::format;
+use bevy_app::prelude::*;
+use bevy_ecs::{entity::hash_map::EntityHashMap, prelude::*, system::RegistryDiagnostics};
+use bevy_platform_support::time::Instant;
+
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+
+/// Adds a `registered_system/<system name>/<entity>` diagnostic to an App for every one-shot system
+/// run with [`World::run_system`] or [`Commands::run_system`], measuring how long its runs take in
+/// milliseconds. The [`Entity`] of the system's [`SystemId`](bevy_ecs::system::SystemId) tells
+/// apart several registrations of the same system.
+///
+/// This inserts the [`RegistryDiagnostics`] resource, which records the runs. The diagnostic of a
+/// system is registered the first time it runs, and gets a measurement in every frame in which it
+/// ran at least once.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct RegisteredSystemDiagnosticsPlugin;
+
+impl Plugin for RegisteredSystemDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RegistryDiagnostics>()
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl RegisteredSystemDiagnosticsPlugin {
+    /// Returns the [`DiagnosticPath`] measuring the runs of the registered system of the given name
+    /// and [`Entity`].
+    pub fn registered_system_path(name: &str, entity: Entity) -> DiagnosticPath {
+        DiagnosticPath::new(format!("registered_system/{name}/{entity}"))
+    }
+
+    /// Records the duration of the registered systems that ran since the last time this ran.
+    pub fn diagnostic_system(
+        registry_diagnostics: Res<RegistryDiagnostics>,
+        mut store: ResMut<DiagnosticsStore>,
+        mut run_counts: Local<EntityHashMap<(u64, DiagnosticPath)>>,
+    ) {
+        // Forget the systems that were unregistered.
+        run_counts.retain(|entity, _| registry_diagnostics.contains(*entity));
+
+        let time = Instant::now();
+        for (entity, runs) in registry_diagnostics.iter() {
+            let (run_count, path) = run_counts
+                .entry(entity)
+                .or_insert_with(|| (0, Self::registered_system_path(&runs.name, entity)));
+            if *run_count == runs.run_count {
+                continue;
+            }
+            *run_count = runs.run_count;
+
+            if store.get(path).is_none() {
+                store.add(Diagnostic::new(path.clone()).with_suffix("ms"));
+            }
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: runs.last_duration.as_secs_f64() * 1000.0,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use alloc::{borrow::Cow, format, vec, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+use bevy_platform_support::{collections::HashMap, time::Instant};
+use core::fmt;
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, Diagnostics, DiagnosticsStore,
+    RegisterDiagnostic,
+};
+
+/// Adds "schedule time" and "system time" diagnostics for each of the given schedules to an App,
+/// measuring how long the schedule and each of its systems took to run in milliseconds.
+///
+/// The measurements are taken in [`Last`] from [`Schedule::last_run_duration`] and
+/// [`Schedule::last_system_durations`], so schedules that are running at that point, such as
+/// [`Main`] and [`Last`] itself, can't be measured. The schedule time only covers running the
+/// systems of the schedule, not initializing newly added systems beforehand.
+///
+/// Each schedule diagnostic is named `schedule_time/<label>`, e.g. `schedule_time/Update`, and
+/// each system diagnostic `system_time/<label>/<system name>`. System diagnostics are registered
+/// the first time the system runs, and their mean and maximum over the diagnostic history can be
+/// printed with [`ScheduleTimeDiagnosticsPlugin::system_time_report`].
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct ScheduleTimeDiagnosticsPlugin {
+    /// The schedules to measure.
+    pub schedules: Vec<InternedScheduleLabel>,
+}
+
+impl Default for ScheduleTimeDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: vec![
+                First.intern(),
+                PreUpdate.intern(),
+                RunFixedMainLoop.intern(),
+                Update.intern(),
+                PostUpdate.intern(),
+            ],
+        }
+    }
+}
+
+impl ScheduleTimeDiagnosticsPlugin {
+    /// Returns the [`DiagnosticPath`] of the diagnostic measuring the given schedule.
+    pub fn schedule_time_path(label: impl ScheduleLabel) -> DiagnosticPath {
+        DiagnosticPath::new(format!("schedule_time/{:?}", label.intern()))
+    }
+
+    /// Returns the [`DiagnosticPath`] of the diagnostic measuring the system of the given name
+    /// in the given schedule.
+    pub fn system_time_path(label: impl ScheduleLabel, system_name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("system_time/{:?}/{system_name}", label.intern()))
+    }
+
+    /// Returns the mean and maximum time of every measured system over the history of its
+    /// diagnostic, sorted from the slowest to the fastest mean.
+    pub fn system_time_report(store: &DiagnosticsStore) -> SystemTimeReport {
+        let mut systems: Vec<SystemTimeStats> = store
+            .iter()
+            .filter(|diagnostic| diagnostic.path().as_str().starts_with("system_time/"))
+            .filter_map(|diagnostic| {
+                Some(SystemTimeStats {
+                    path: diagnostic.path().clone(),
+                    mean: diagnostic.average()?,
+                    max: diagnostic.values().copied().reduce(f64::max)?,
+                })
+            })
+            .collect();
+        systems.sort_by(|a, b| b.mean.total_cmp(&a.mean));
+        SystemTimeReport { systems }
+    }
+
+    /// Records the run time of the measured schedules.
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        schedules: Res<Schedules>,
+        measured: Res<MeasuredSchedules>,
+    ) {
+        for (label, path) in &measured.0 {
+            if let Some(duration) = schedules.get(*label).and_then(Schedule::last_run_duration) {
+                diagnostics.add_measurement(path, || duration.as_secs_f64() * 1000.0);
+            }
+        }
+    }
+
+    /// Records the run time of the systems of the measured schedules, registering the diagnostic of
+    /// each system the first time it runs.
+    pub fn system_diagnostic_system(
+        mut store: ResMut<DiagnosticsStore>,
+        schedules: Res<Schedules>,
+        measured: Res<MeasuredSchedules>,
+        mut paths: Local<HashMap<(InternedScheduleLabel, Cow<'static, str>), DiagnosticPath>>,
+    ) {
+        let time = Instant::now();
+        for &(label, _) in &measured.0 {
+            let Some(schedule) = schedules.get(label) else {
+                continue;
+            };
+            for (name, duration) in schedule.last_system_durations() {
+                let path = paths
+                    .entry((label, name))
+                    .or_insert_with_key(|(label, name)| Self::system_time_path(*label, name));
+                if store.get(path).is_none() {
+                    store.add(Diagnostic::new(path.clone()).with_suffix("ms"));
+                }
+                if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                    diagnostic.add_measurement(DiagnosticMeasurement {
+                        time,
+                        value: duration.as_secs_f64() * 1000.0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// The time taken by a system measured by the [`ScheduleTimeDiagnosticsPlugin`].
+#[derive(Debug, Clone)]
+pub struct SystemTimeStats {
+    /// The path of the diagnostic measuring the system.
+    pub path: DiagnosticPath,
+    /// The mean time taken by the system, in milliseconds.
+    pub mean: f64,
+    /// The maximum time taken by the system, in milliseconds.
+    pub max: f64,
+}
+
+/// The time taken by the systems measured by the [`ScheduleTimeDiagnosticsPlugin`], sorted from
+/// the slowest to the fastest. Its [`Display`](fmt::Display) implementation prints one system
+/// per line.
+///
+/// Returned by [`ScheduleTimeDiagnosticsPlugin::system_time_report`].
+#[derive(Debug, Clone, Default)]
+pub struct SystemTimeReport {
+    /// The measured systems, sorted by decreasing mean time.
+    pub systems: Vec<SystemTimeStats>,
+}
+
+impl fmt::Display for SystemTimeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for system in &self.systems {
+            writeln!(
+                f,
+                "{:>10.4}ms mean {:>10.4}ms max  {}",
+                system.mean, system.max, system.path
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The schedules measured by the [`ScheduleTimeDiagnosticsPlugin`], with their diagnostic paths.
+#[derive(Resource)]
+pub struct MeasuredSchedules(Vec<(InternedScheduleLabel, DiagnosticPath)>);
+
+impl Plugin for ScheduleTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let mut measured = Vec::with_capacity(self.schedules.len());
+        for &label in &self.schedules {
+            let path = Self::schedule_time_path(label);
+            app.register_diagnostic(Diagnostic::new(path.clone()).with_suffix("ms"))
+                .edit_schedule(label, |schedule| {
+                    schedule.set_measure_systems(true);
+                });
+            measured.push((label, path));
+        }
+        app.insert_resource(MeasuredSchedules(measured))
+            .add_systems(
+                Last,
+                (Self::diagnostic_system, Self::system_diagnostic_system),
+            );
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::{format, vec, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    component::{ComponentId, Tick},
+    entity::Entity,
+    system::Local,
+    world::World,
+};
+use bevy_platform_support::{collections::HashMap, time::Instant};
+use core::mem::size_of;
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+
+/// Adds "archetype count", "table count" and "table memory" diagnostics to an App, as well as
+/// a `component_memory/<component name>` diagnostic for every component stored in the world.
+///
+/// These help to catch entity leaks and archetype fragmentation in long running sessions.
+/// The per-component diagnostics are registered the first time a component is stored, and
+/// estimate the memory taken by its storage. Combine this with the
+/// [`EntityCountDiagnosticsPlugin`](crate::EntityCountDiagnosticsPlugin) to also track the total
+/// number of entities.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct StorageDiagnosticsPlugin;
+
+impl Plugin for StorageDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_MEMORY).with_suffix(" bytes"))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl StorageDiagnosticsPlugin {
+    pub const ARCHETYPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("archetype_count");
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("table_count");
+    pub const TABLE_MEMORY: DiagnosticPath = DiagnosticPath::const_new("table_memory");
+
+    /// Returns the [`DiagnosticPath`] measuring the storage of the component of the given name.
+    pub fn component_memory_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("component_memory/{name}"))
+    }
+
+    /// Records the storage diagnostics.
+    ///
+    /// The memory is an estimate based on the allocated capacity of every table and the number of
+    /// components in sparse sets, and doesn't include heap memory owned by the components
+    /// themselves.
+    pub fn diagnostic_system(
+        world: &mut World,
+        mut paths: Local<HashMap<ComponentId, DiagnosticPath>>,
+    ) {
+        let archetypes = world.archetypes();
+        let tables = &world.storages().tables;
+        let components = world.components();
+
+        let item_size = |component_id: ComponentId| {
+            components
+                .get_info(component_id)
+                .map_or(0, |info| info.layout().size() + 2 * size_of::<Tick>())
+        };
+        let mut component_memory = vec![0; components.len()];
+        let mut table_memory = 0;
+        let mut measured_tables = vec![false; tables.len()];
+        for archetype in archetypes.iter() {
+            for component_id in archetype.sparse_set_components() {
+                component_memory[component_id.index()] += archetype.len() * item_size(component_id);
+            }
+            // Tables are shared by archetypes that only differ in their sparse set components.
+            let table_id = archetype.table_id();
+            if measured_tables[table_id.as_usize()] {
+                continue;
+            }
+            measured_tables[table_id.as_usize()] = true;
+            let capacity = tables[table_id].entity_capacity();
+            table_memory += capacity * size_of::<Entity>();
+            for component_id in archetype.table_components() {
+                let memory = capacity * item_size(component_id);
+                component_memory[component_id.index()] += memory;
+                table_memory += memory;
+            }
+        }
+
+        let measurements = [
+            (Self::ARCHETYPE_COUNT, archetypes.len() as f64),
+            (Self::TABLE_COUNT, tables.len() as f64),
+            (Self::TABLE_MEMORY, table_memory as f64),
+        ];
+        let component_measurements: Vec<_> = components
+            .iter()
+            .filter_map(|info| {
+                let memory = component_memory[info.id().index()];
+                // Only start tracking components once they are stored.
+                if memory == 0 && !paths.contains_key(&info.id()) {
+                    return None;
+                }
+                paths
+                    .entry(info.id())
+                    .or_insert_with(|| Self::component_memory_path(info.name()));
+                Some((info.id(), memory as f64))
+            })
+            .collect();
+
+        let time = Instant::now();
+        let Some(mut store) = world.get_resource_mut::<DiagnosticsStore>() else {
+            return;
+        };
+        for (path, value) in measurements {
+            if let Some(diagnostic) = store.get_mut(&path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        }
+        for (component_id, value) in component_measurements {
+            let path = &paths[&component_id];
+            if store.get(path).is_none() {
+                store.add(Diagnostic::new(path.clone()).with_suffix(" bytes"));
+            }
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::component::Component;
+
+    #[derive(Component)]
+    struct Position([f32; 3]);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Marker(u64);
+
+    #[test]
+    fn component_memory() {
+        let mut app = App::new();
+        app.add_plugins(StorageDiagnosticsPlugin);
+        app.world_mut()
+            .spawn_batch((0..10).map(|_| (Position([0.0; 3]), Marker(0))));
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let memory = |name: &str| {
+            store
+                .get(&StorageDiagnosticsPlugin::component_memory_path(name))
+                .and_then(Diagnostic::value)
+                .unwrap()
+        };
+        let item_size = |size: usize| (size + 2 * size_of::<Tick>()) as f64;
+        assert_eq!(
+            memory(core::any::type_name::<Marker>()),
+            10.0 * item_size(8)
+        );
+        // Tables may allocate room for more entities than they store.
+        assert!(memory(core::any::type_name::<Position>()) >= 10.0 * item_size(12));
+        assert!(store
+            .get(&StorageDiagnosticsPlugin::TABLE_MEMORY)
+            .and_then(Diagnostic::value)
+            .is_some_and(|memory| memory >= 10.0 * item_size(12)));
+    }
+}
@@ -786,6 +786,56 @@ impl SparseSetIndex for ArchetypeComponentId {
 /// along with an [`ArchetypeRecord`] which contains some metadata about how the component is stored in the archetype.
 pub type ComponentIndex = HashMap<ComponentId, HashMap<ArchetypeId, ArchetypeRecord>>;
 
+/// A rule every [`Archetype`] of a [`World`] must follow, registered with
+/// [`World::register_archetype_invariant`].
+///
+/// Invariants are checked whenever a new archetype is created, so breaking one panics as soon as
+/// an entity would end up with an invalid set of components.
+///
+/// To automatically insert a component alongside another one, use
+/// [required components](crate::component::Component#required-components) instead.
+///
+/// [`World`]: crate::world::World
+/// [`World::register_archetype_invariant`]: crate::world::World::register_archetype_invariant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArchetypeInvariant {
+    /// Archetypes containing the first component must also contain the second one.
+    Requires(ComponentId, ComponentId),
+    /// Archetypes must not contain both components.
+    MutuallyExclusive(ComponentId, ComponentId),
+}
+
+impl ArchetypeInvariant {
+    /// Returns `true` if an archetype made of the given components follows this invariant.
+    pub fn is_satisfied_by(&self, contains: impl Fn(ComponentId) -> bool) -> bool {
+        match *self {
+            Self::Requires(a, b) => !contains(a) || contains(b),
+            Self::MutuallyExclusive(a, b) => !(contains(a) && contains(b)),
+        }
+    }
+
+    /// Panics with a message naming the components if an archetype made of the given components
+    /// breaks this invariant.
+    fn assert_satisfied_by(&self, components: &Components, contains: impl Fn(ComponentId) -> bool) {
+        if self.is_satisfied_by(contains) {
+            return;
+        }
+        let name = |id| components.get_name(id).unwrap_or("<unknown>");
+        match *self {
+            Self::Requires(a, b) => panic!(
+                "Archetype invariant broken: {} requires {}, but an archetype was created with only the former",
+                name(a),
+                name(b)
+            ),
+            Self::MutuallyExclusive(a, b) => panic!(
+                "Archetype invariant broken: {} and {} are mutually exclusive, but an archetype was created with both",
+                name(a),
+                name(b)
+            ),
+        }
+    }
+}
+
 /// The backing store of all [`Archetype`]s within a [`World`].
 ///
 /// For more information, see the *[module level documentation]*.
@@ -799,6 +849,8 @@ pub struct Archetypes {
     by_components: HashMap<ArchetypeComponents, ArchetypeId>,
     /// find all the archetypes that contain a component
     pub(crate) by_component: ComponentIndex,
+    /// rules checked for every new archetype
+    invariants: Vec<ArchetypeInvariant>,
 }
 
 /// Metadata about how a component is stored in an [`Archetype`].
@@ -819,6 +871,7 @@ impl Archetypes {
             by_components: Default::default(),
             by_component: Default::default(),
             archetype_component_count: 0,
+            invariants: Vec::new(),
         };
         // SAFETY: Empty archetype has no components
         unsafe {
@@ -920,9 +973,33 @@ impl Archetypes {
         self.archetypes.iter()
     }
 
+    /// Returns the [`ArchetypeInvariant`]s every archetype must follow.
+    #[inline]
+    pub fn invariants(&self) -> &[ArchetypeInvariant] {
+        &self.invariants
+    }
+
+    /// Adds an [`ArchetypeInvariant`] checked for every new archetype.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an existing archetype already breaks the invariant.
+    pub(crate) fn add_invariant(&mut self, components: &Components, invariant: ArchetypeInvariant) {
+        for archetype in &self.archetypes {
+            invariant.assert_satisfied_by(components, |id| archetype.contains(id));
+        }
+        if !self.invariants.contains(&invariant) {
+            self.invariants.push(invariant);
+        }
+    }
+
     /// Gets the archetype id matching the given inputs or inserts a new one if it doesn't exist.
     /// `table_components` and `sparse_set_components` must be sorted
     ///
+    /// # Panics
+    ///
+    /// Panics if a new archetype would break one of the registered [`ArchetypeInvariant`]s.
+    ///
     /// # Safety
     /// [`TableId`] must exist in tables
     /// `table_components` and `sparse_set_components` must exist in `components`
@@ -942,6 +1019,7 @@ impl Archetypes {
         let archetypes = &mut self.archetypes;
         let archetype_component_count = &mut self.archetype_component_count;
         let component_index = &mut self.by_component;
+        let invariants = &self.invariants;
         let archetype_id = *self
             .by_components
             .entry(archetype_identity)
@@ -950,6 +1028,12 @@ impl Archetypes {
                     table_components,
                     sparse_set_components,
                 } = identity;
+                for invariant in invariants {
+                    invariant.assert_satisfied_by(components, |id| {
+                        table_components.binary_search(&id).is_ok()
+                            || sparse_set_components.binary_search(&id).is_ok()
+                    });
+                }
                 let id = ArchetypeId::new(archetypes.len());
                 let table_start = *archetype_component_count;
                 *archetype_component_count += table_components.len();
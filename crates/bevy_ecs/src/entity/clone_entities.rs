@@ -1,4 +1,4 @@
-use alloc::{borrow::ToOwned, collections::VecDeque, vec::Vec};
+use alloc::{borrow::ToOwned, collections::VecDeque, string::String, vec::Vec};
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_ptr::{Ptr, PtrMut};
 use bumpalo::Bump;
@@ -9,9 +9,10 @@ use alloc::boxed::Box;
 
 use crate::component::{ComponentCloneBehavior, ComponentCloneFn};
 use crate::entity::hash_map::EntityHashMap;
-use crate::entity::{Entities, EntityMapper};
+use crate::entity::{Entities, EntityDoesNotExistError, EntityMapper};
 use crate::relationship::RelationshipInsertHookMode;
 use crate::system::Commands;
+use crate::world::CommandQueue;
 use crate::{
     bundle::Bundle,
     component::{Component, ComponentId, ComponentInfo},
@@ -403,6 +404,21 @@ impl<'a> BundleScratch<'a> {
         self.component_ptrs.push(PtrMut::from(component_ref));
     }
 
+    /// Drops the scratch components without writing them anywhere.
+    ///
+    /// # Safety
+    /// All [`ComponentId`] values in this instance must come from `world`.
+    pub(crate) unsafe fn drop_components(self, world: &World) {
+        for (id, ptr) in self.component_ids.into_iter().zip(self.component_ptrs) {
+            // SAFETY: The caller ensures that `id` is from `world`.
+            let info = unsafe { world.components().get_info_unchecked(id) };
+            if let Some(drop) = info.drop() {
+                // SAFETY: `ptr` holds uniquely-owned data of the type of `id`.
+                unsafe { drop(ptr.promote()) };
+            }
+        }
+    }
+
     /// Writes the scratch components to the given entity in the given world.
     ///
     /// # Safety
@@ -605,6 +621,224 @@ impl EntityCloner {
     }
 }
 
+/// An error returned by [`World::move_entities`]. Nothing is moved when it occurs.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum MoveEntitiesError {
+    /// One of the entities to move doesn't exist in the source world.
+    #[error(transparent)]
+    EntityDoesNotExist(#[from] EntityDoesNotExistError),
+    /// Some components of the entities to move can't be moved to the target world, because their
+    /// type isn't registered there or their [`ComponentCloneBehavior`] doesn't write them.
+    #[error("These components can't be moved to the target world: {}", .0.join(", "))]
+    UnmovableComponents(Vec<String>),
+}
+
+impl World {
+    /// Moves `entities`, with all of their components, from this world to `target`.
+    ///
+    /// Every entity is spawned in `target` and mapped to its new [`Entity`] with `mapper`, which
+    /// also maps the [`Entity`] references in the moved components. The components are written
+    /// with their [`ComponentCloneBehavior`], like [`EntityCloner`] does, after which the entities
+    /// are despawned from this world. Relationships between the moved entities are kept, and
+    /// [`RelationshipTarget`](crate::relationship::RelationshipTarget)s are rebuilt from the moved
+    /// relationships. Despawning follows the usual rules, so entities related to a moved entity
+    /// through a [linked spawn](crate::relationship::RelationshipTarget::LINKED_SPAWN), such as its
+    /// [`Children`](crate::hierarchy::Children), are despawned as well unless they are moved along.
+    ///
+    /// A component type that isn't registered in `target` yet is registered through its
+    /// [`ReflectComponent`](crate::reflect::ReflectComponent) in this world's
+    /// [`AppTypeRegistry`](crate::reflect::AppTypeRegistry).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, without moving anything, if one of the entities doesn't exist, or with
+    /// the names of all the components that can't be moved to `target`.
+    pub fn move_entities(
+        &mut self,
+        target: &mut World,
+        entities: impl IntoIterator<Item = Entity>,
+        mapper: &mut impl EntityMapper,
+    ) -> Result<(), MoveEntitiesError> {
+        let entities: Vec<Entity> = entities.into_iter().collect();
+
+        #[cfg(feature = "bevy_reflect")]
+        let app_registry = self
+            .get_resource::<crate::reflect::AppTypeRegistry>()
+            .cloned();
+        #[cfg(not(feature = "bevy_reflect"))]
+        let app_registry = Option::<()>::None;
+
+        // Find the id of every component in `target` before changing anything.
+        let mut target_ids = HashMap::<ComponentId, ComponentId>::default();
+        let mut unmovable = Vec::new();
+        for &entity in &entities {
+            for component in self.get_entity(entity)?.archetype().components() {
+                if target_ids.contains_key(&component) {
+                    continue;
+                }
+                // SAFETY: This component exists because it is present on the archetype.
+                let info = unsafe { self.components().get_info_unchecked(component) };
+                let target_id = info.type_id().and_then(|type_id| {
+                    target.components().get_id(type_id).or_else(|| {
+                        register_reflect_component(target, app_registry.as_ref(), type_id)
+                    })
+                });
+                match target_id {
+                    Some(target_id) => {
+                        target_ids.insert(component, target_id);
+                    }
+                    None => push_unmovable(&mut unmovable, info),
+                }
+            }
+        }
+        if !unmovable.is_empty() {
+            return Err(MoveEntitiesError::UnmovableComponents(unmovable));
+        }
+
+        // Spawn all entities first, so that references between them can be mapped.
+        for &entity in &entities {
+            let moved = target.spawn_empty().id();
+            mapper.set_mapped(entity, moved);
+        }
+
+        let bundle_scratch_allocator = Bump::new();
+        let mut bundle_scratches = Vec::with_capacity(entities.len());
+        let mut entity_cloner = EntityCloner::default();
+        // Commands queued by clone handlers refer to this world, so they are dropped.
+        let mut command_queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new_from_entities(&mut command_queue, self.entities());
+            for &source in &entities {
+                let moved = mapper.get_mapped(source);
+                let source_entity = self.entity(source);
+                let archetype = source_entity.archetype();
+                let mut bundle_scratch = BundleScratch::with_capacity(archetype.component_count());
+                for component in archetype.components() {
+                    // SAFETY: This component exists because it is present on the archetype.
+                    let info = unsafe { self.components().get_info_unchecked(component) };
+                    let handler = info
+                        .clone_behavior()
+                        .resolve(entity_cloner.default_clone_fn);
+                    let source_component = SourceComponent {
+                        info,
+                        // SAFETY: `component` is from `source_entity`'s archetype
+                        ptr: unsafe { source_entity.get_by_id(component).debug_checked_unwrap() },
+                    };
+
+                    // SAFETY:
+                    // - `info` and `component` are from the same world
+                    // - `source_component.ptr` is valid and points to the same type as represented by `component`
+                    let mut ctx = unsafe {
+                        ComponentCloneCtx::new(
+                            component,
+                            source,
+                            moved,
+                            &bundle_scratch_allocator,
+                            &mut bundle_scratch,
+                            self.entities(),
+                            info,
+                            &mut entity_cloner,
+                            &mut *mapper,
+                            app_registry.as_ref(),
+                        )
+                    };
+                    (handler)(&mut commands, &source_component, &mut ctx);
+                    if !ctx.target_component_written() {
+                        push_unmovable(&mut unmovable, info);
+                    }
+                }
+                bundle_scratches.push((moved, bundle_scratch));
+            }
+        }
+        // SAFETY: `command_queue` is a local that is still alive.
+        unsafe { command_queue.get_raw().apply_or_drop_queued(None) };
+
+        if !unmovable.is_empty() {
+            for (moved, bundle_scratch) in bundle_scratches {
+                // SAFETY: All `component_ids` are from this world and match their data.
+                unsafe { bundle_scratch.drop_components(self) };
+                target.despawn(moved);
+            }
+            return Err(MoveEntitiesError::UnmovableComponents(unmovable));
+        }
+
+        for (moved, bundle_scratch) in bundle_scratches {
+            let mut component_ids = Vec::with_capacity(bundle_scratch.component_ids.len());
+            let mut component_ptrs = Vec::with_capacity(bundle_scratch.component_ids.len());
+            let mut already_inserted = BundleScratch::with_capacity(0);
+            for (component, ptr) in bundle_scratch
+                .component_ids
+                .into_iter()
+                .zip(bundle_scratch.component_ptrs)
+            {
+                let target_id = target_ids[&component];
+                // A relationship moved before may already have inserted its `RelationshipTarget`
+                // on this entity, which must not be replaced by the empty one written for it.
+                if target.entity(moved).contains_id(target_id) {
+                    // SAFETY: `component` is from this world and matches the data of `ptr`.
+                    unsafe { already_inserted.push_ptr(component, ptr) };
+                } else {
+                    component_ids.push(target_id);
+                    component_ptrs.push(ptr);
+                }
+            }
+            // SAFETY: All `component_ids` are from this world and match their data.
+            unsafe { already_inserted.drop_components(self) };
+
+            // SAFETY:
+            // - All `component_ids` have been mapped to their ids in `target`.
+            // - All `component_ptrs` are valid types represented by `component_ids`
+            unsafe {
+                BundleScratch {
+                    component_ids,
+                    component_ptrs,
+                }
+                .write(target, moved, RelationshipInsertHookMode::Run);
+            }
+        }
+        target.flush();
+
+        // Entities may already have been despawned along with an entity moved before them.
+        for entity in entities {
+            if let Ok(entity) = self.get_entity_mut(entity) {
+                entity.despawn();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds the name of a component that [`World::move_entities`] can't move, once.
+fn push_unmovable(unmovable: &mut Vec<String>, info: &ComponentInfo) {
+    if !unmovable.iter().any(|name| name == info.name()) {
+        unmovable.push(info.name().to_owned());
+    }
+}
+
+/// Registers the component type of `type_id` in `world` through its
+/// [`ReflectComponent`](crate::reflect::ReflectComponent), if it has one in `registry`.
+#[cfg(feature = "bevy_reflect")]
+fn register_reflect_component(
+    world: &mut World,
+    registry: Option<&crate::reflect::AppTypeRegistry>,
+    type_id: TypeId,
+) -> Option<ComponentId> {
+    let reflect_component = registry?
+        .read()
+        .get_type_data::<crate::reflect::ReflectComponent>(type_id)?
+        .clone();
+    Some(reflect_component.register_component(world))
+}
+
+#[cfg(not(feature = "bevy_reflect"))]
+fn register_reflect_component(
+    _world: &mut World,
+    _registry: Option<&()>,
+    _type_id: TypeId,
+) -> Option<ComponentId> {
+    None
+}
+
 /// A builder for configuring [`EntityCloner`]. See [`EntityCloner`] for more information.
 #[derive(Debug)]
 pub struct EntityClonerBuilder<'w> {
@@ -1054,6 +1288,24 @@ mod tests {
             assert_eq!(world.get::<A>(e_clone), None);
             assert_eq!(world.get::<B>(e_clone), None);
         }
+
+        #[test]
+        fn move_entities_registers_reflected_components() {
+            #[derive(Component, Reflect, Clone, PartialEq, Debug)]
+            #[reflect(Component)]
+            struct A(usize);
+
+            let mut source = World::default();
+            source.init_resource::<AppTypeRegistry>();
+            source.resource::<AppTypeRegistry>().write().register::<A>();
+            let e = source.spawn(A(5)).id();
+
+            let mut target = World::default();
+            let mut map = EntityHashMap::<Entity>::default();
+            source.move_entities(&mut target, [e], &mut map).unwrap();
+
+            assert_eq!(target.get::<A>(map[&e]), Some(&A(5)));
+        }
     }
 
     #[test]
@@ -1375,4 +1627,77 @@ mod tests {
         assert_eq!(*world.entity(cloned).get::<SomeRef>().unwrap(), SomeRef(b));
         assert!(world.resource::<FromWorldCalled>().0);
     }
+
+    #[test]
+    fn move_entities() {
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct A(usize);
+
+        let mut source = World::default();
+        let parent = source.spawn(A(1)).id();
+        let child = source.spawn((A(2), ChildOf { parent })).id();
+
+        let mut target = World::default();
+        target.register_component::<A>();
+        target.register_component::<ChildOf>();
+        target.register_component::<Children>();
+
+        // The child is written first, so its parent already has `Children` when it is written.
+        let mut map = EntityHashMap::<Entity>::default();
+        source
+            .move_entities(&mut target, [child, parent], &mut map)
+            .unwrap();
+
+        assert!(source.get_entity(parent).is_err());
+        assert!(source.get_entity(child).is_err());
+        let (moved_parent, moved_child) = (map[&parent], map[&child]);
+        assert_eq!(target.get::<A>(moved_parent), Some(&A(1)));
+        assert_eq!(target.get::<A>(moved_child), Some(&A(2)));
+        assert_eq!(
+            target.get::<ChildOf>(moved_child).unwrap().parent,
+            moved_parent
+        );
+        assert_eq!(
+            &**target.get::<Children>(moved_parent).unwrap(),
+            &[moved_child]
+        );
+    }
+
+    #[test]
+    fn move_entities_unmovable_components() {
+        use crate::entity::MoveEntitiesError;
+        use alloc::string::ToString;
+
+        #[derive(Component, Clone)]
+        struct Unregistered;
+
+        #[derive(Component)]
+        struct NotClone;
+
+        let mut source = World::default();
+        let e = source.spawn((Unregistered, NotClone)).id();
+
+        let mut target = World::default();
+        target.register_component::<NotClone>();
+        let mut map = EntityHashMap::<Entity>::default();
+        let error = source
+            .move_entities(&mut target, [e], &mut map)
+            .unwrap_err();
+        let MoveEntitiesError::UnmovableComponents(names) = &error else {
+            panic!("expected unmovable components, got {error}");
+        };
+        assert_eq!(names.len(), 1);
+        assert!(names[0].ends_with("Unregistered"));
+
+        // Once every type is registered, components that can't be cloned are reported.
+        target.register_component::<Unregistered>();
+        let error = source
+            .move_entities(&mut target, [e], &mut map)
+            .unwrap_err();
+        assert!(error.to_string().contains("NotClone"));
+
+        // Nothing was moved.
+        assert!(source.get::<Unregistered>(e).is_some());
+        assert_eq!(target.entities().len(), 0);
+    }
 }
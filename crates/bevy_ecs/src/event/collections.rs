@@ -213,6 +213,24 @@ impl<E: Event> Events<E> {
         iter.map(|e| e.event)
     }
 
+    /// Drops the oldest events until at most `capacity` remain, regardless of when they were sent.
+    ///
+    /// This can be called instead of [`Events::update`] to keep a fixed number of events around,
+    /// for example for systems that only run every few frames.
+    pub fn retain_latest(&mut self, capacity: usize) {
+        let mut events = core::mem::take(&mut self.events_a.events);
+        events.append(&mut self.events_b.events);
+        let dropped = events.len().saturating_sub(capacity);
+        events.drain(..dropped);
+        self.events_a.start_event_count = self.event_count - events.len();
+        self.events_a.events = events;
+        self.events_b.start_event_count = self.event_count;
+        debug_assert_eq!(
+            self.events_a.start_event_count + self.events_a.len(),
+            self.events_b.start_event_count
+        );
+    }
+
     #[inline]
     fn reset_start_event_count(&mut self) {
         self.events_a.start_event_count = self.event_count;
@@ -419,4 +437,32 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn retain_latest_keeps_most_recent_events() {
+        #[derive(Event, Clone, Debug, PartialEq)]
+        struct TestEvent(usize);
+
+        let mut test_events = Events::<TestEvent>::default();
+        let mut cursor = test_events.get_cursor();
+
+        for i in 0..3 {
+            test_events.send(TestEvent(i));
+            test_events.retain_latest(5);
+        }
+        // Events older than two updates are still kept.
+        assert_eq!(test_events.len(), 3);
+
+        test_events.send_batch((3..6).map(TestEvent));
+        test_events.retain_latest(4);
+        assert_eq!(test_events.len(), 4);
+        assert_eq!(test_events.oldest_event_count(), 2);
+        assert_eq!(
+            cursor
+                .read(&test_events)
+                .cloned()
+                .collect::<alloc::vec::Vec<_>>(),
+            (2..6).map(TestEvent).collect::<alloc::vec::Vec<_>>()
+        );
+    }
 }
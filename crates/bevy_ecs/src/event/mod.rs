@@ -23,7 +23,7 @@ pub use mut_iterators::EventMutParIter;
 pub use mut_iterators::{EventMutIterator, EventMutIteratorWithId};
 pub use mutator::EventMutator;
 pub use reader::EventReader;
-pub use registry::{EventRegistry, ShouldUpdateEvents};
+pub use registry::{EventRegistry, EventRetention, ShouldUpdateEvents};
 pub use update::{
     event_update_condition, event_update_system, signal_event_update_system, EventUpdates,
 };
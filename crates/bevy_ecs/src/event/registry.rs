@@ -12,9 +12,10 @@ struct RegisteredEvent {
     component_id: ComponentId,
     // Required to flush the secondary buffer and drop events even if left unchanged.
     previously_updated: bool,
+    retention: EventRetention,
     // SAFETY: The component ID and the function must be used to fetch the Events<T> resource
     // of the same type initialized in `register_event`, or improper type casts will occur.
-    update: unsafe fn(MutUntyped),
+    update: unsafe fn(MutUntyped, EventRetention),
 }
 
 /// A registry of all of the [`Events`] in the [`World`], used by [`event_update_system`](crate::event::update::event_update_system)
@@ -40,12 +41,36 @@ pub enum ShouldUpdateEvents {
     Ready,
 }
 
+/// Controls how long the events of a type registered in an [`EventRegistry`] are kept.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventRetention {
+    /// Events are dropped after two updates, see [`Events`] for details.
+    #[default]
+    TwoUpdates,
+    /// Only the given number of most recent events are kept on each update,
+    /// no matter how many updates ago they were sent.
+    Capacity(usize),
+    /// Events are never dropped automatically.
+    ///
+    /// They must be removed with [`Events::clear`], [`Events::drain`] or [`Events::update`],
+    /// or the buffers will grow indefinitely.
+    Manual,
+}
+
 impl EventRegistry {
     /// Registers an event type to be updated in a given [`World`]
     ///
     /// If no instance of the [`EventRegistry`] exists in the world, this will add one - otherwise it will use
     /// the existing instance.
     pub fn register_event<T: Event>(world: &mut World) {
+        Self::register_event_with_retention::<T>(world, EventRetention::default());
+    }
+
+    /// Registers an event type to be updated in a given [`World`] according to the given [`EventRetention`].
+    ///
+    /// If no instance of the [`EventRegistry`] exists in the world, this will add one - otherwise it will use
+    /// the existing instance.
+    pub fn register_event_with_retention<T: Event>(world: &mut World, retention: EventRetention) {
         // By initializing the resource here, we can be sure that it is present,
         // and receive the correct, up-to-date `ComponentId` even if it was previously removed.
         let component_id = world.init_resource::<Events<T>>();
@@ -53,15 +78,36 @@ impl EventRegistry {
         registry.event_updates.push(RegisteredEvent {
             component_id,
             previously_updated: false,
-            update: |ptr| {
+            retention,
+            update: |ptr, retention| {
                 // SAFETY: The resource was initialized with the type Events<T>.
-                unsafe { ptr.with_type::<Events<T>>() }
-                    .bypass_change_detection()
-                    .update();
+                let events = unsafe { ptr.with_type::<Events<T>>() }.bypass_change_detection();
+                match retention {
+                    EventRetention::TwoUpdates => events.update(),
+                    EventRetention::Capacity(capacity) => events.retain_latest(capacity),
+                    EventRetention::Manual => {}
+                }
             },
         });
     }
 
+    /// Changes the [`EventRetention`] of an event type, registering it if it wasn't already.
+    pub fn set_retention<T: Event>(world: &mut World, retention: EventRetention) {
+        let component_id = world.init_resource::<Events<T>>();
+        let mut registry = world.get_resource_or_init::<Self>();
+        let mut registered = false;
+        for registered_event in &mut registry.event_updates {
+            if registered_event.component_id == component_id {
+                registered_event.retention = retention;
+                registered = true;
+            }
+        }
+        // The `Events<T>` resource may have been initialized without registering the event.
+        if !registered {
+            Self::register_event_with_retention::<T>(world, retention);
+        }
+    }
+
     /// Updates all of the registered events in the World.
     pub fn run_updates(&mut self, world: &mut World, last_change_tick: Tick) {
         for registered_event in &mut self.event_updates {
@@ -71,7 +117,7 @@ impl EventRegistry {
                 if registered_event.previously_updated || has_changed {
                     // SAFETY: The update function pointer is called with the resource
                     // fetched from the same component ID.
-                    unsafe { (registered_event.update)(events) };
+                    unsafe { (registered_event.update)(events, registered_event.retention) };
                     // Always set to true if the events have changed, otherwise disable running on the second invocation
                     // to wait for more changes.
                     registered_event.previously_updated =
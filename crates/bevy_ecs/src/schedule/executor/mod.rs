@@ -4,7 +4,7 @@ mod simple;
 mod single_threaded;
 
 use alloc::{borrow::Cow, vec, vec::Vec};
-use core::any::TypeId;
+use core::{any::TypeId, time::Duration};
 
 pub use self::{simple::SimpleExecutor, single_threaded::SingleThreadedExecutor};
 
@@ -99,6 +99,11 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Indexed by system node id.
+    /// How long the system took during the last run, or `None` if it didn't run.
+    ///
+    /// Empty if the schedule doesn't measure its systems.
+    pub(super) system_durations: Vec<Option<Duration>>,
 }
 
 impl SystemSchedule {
@@ -114,6 +119,7 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            system_durations: Vec::new(),
         }
     }
 }
@@ -105,6 +105,10 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            #[cfg(feature = "std")]
+            let start = (!schedule.system_durations.is_empty())
+                .then(bevy_platform_support::time::Instant::now);
+
             let f = AssertUnwindSafe(|| {
                 if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
                     error_handler(
@@ -129,6 +133,11 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            #[cfg(feature = "std")]
+            if let Some(start) = start {
+                schedule.system_durations[system_index] = Some(start.elapsed());
+            }
         }
 
         self.evaluated_sets.clear();
@@ -112,6 +112,10 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            #[cfg(feature = "std")]
+            let start = (!schedule.system_durations.is_empty())
+                .then(bevy_platform_support::time::Instant::now);
+
             let f = AssertUnwindSafe(|| {
                 if system.is_exclusive() {
                     if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
@@ -156,6 +160,11 @@ impl SystemExecutor for SingleThreadedExecutor {
                 (f)();
             }
 
+            #[cfg(feature = "std")]
+            if let Some(start) = start {
+                schedule.system_durations[system_index] = Some(start.elapsed());
+            }
+
             self.unapplied_systems.insert(system_index);
         }
 
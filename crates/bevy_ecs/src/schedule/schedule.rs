@@ -3,6 +3,7 @@
     reason = "This instance of module inception is being discussed; see #17344."
 )]
 use alloc::{
+    borrow::Cow,
     boxed::Box,
     collections::{BTreeMap, BTreeSet},
     format,
@@ -15,6 +16,7 @@ use bevy_utils::{default, TypeIdMap};
 use core::{
     any::{Any, TypeId},
     fmt::{Debug, Write},
+    time::Duration,
 };
 use disqualified::ShortName;
 use fixedbitset::FixedBitSet;
@@ -297,6 +299,8 @@ pub struct Schedule {
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
     error_handler: Option<fn(Error, SystemErrorContext)>,
+    last_run_duration: Option<Duration>,
+    measure_systems: bool,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -322,6 +326,8 @@ impl Schedule {
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
             error_handler: None,
+            last_run_duration: None,
+            measure_systems: false,
         };
         // Call `set_build_settings` to add any default build passes
         this.set_build_settings(Default::default());
@@ -333,6 +339,44 @@ impl Schedule {
         self.label
     }
 
+    /// Returns how long running the systems of this schedule took during its last
+    /// [`run`](Schedule::run), including evaluating run conditions and applying deferred system
+    /// buffers.
+    ///
+    /// This doesn't include initializing newly added systems or checking the change ticks of the
+    /// world, which happen before the systems run.
+    ///
+    /// Returns `None` if the schedule hasn't run yet, or if the `std` feature is disabled.
+    pub fn last_run_duration(&self) -> Option<Duration> {
+        self.last_run_duration
+    }
+
+    /// Sets whether the schedule measures how long each of its systems takes to run, which can be
+    /// read with [`Schedule::last_system_durations`].
+    ///
+    /// This is disabled by default, as it adds a small overhead to every system. It has no effect
+    /// if the `std` feature is disabled.
+    pub fn set_measure_systems(&mut self, measure_systems: bool) -> &mut Self {
+        self.measure_systems = measure_systems;
+        self
+    }
+
+    /// Returns the name of each system that ran during the last [`run`](Schedule::run) of this
+    /// schedule, along with how long it took.
+    ///
+    /// The durations are only measured once enabled with [`Schedule::set_measure_systems`].
+    /// Systems that were skipped, for example because their run conditions weren't met, are
+    /// not included.
+    pub fn last_system_durations(
+        &self,
+    ) -> impl Iterator<Item = (Cow<'static, str>, Duration)> + '_ {
+        self.executable
+            .systems
+            .iter()
+            .zip(&self.executable.system_durations)
+            .filter_map(|(system, duration)| Some((system.name(), (*duration)?)))
+    }
+
     /// Add a collection of systems to the schedule.
     pub fn add_systems<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self {
         self.graph.process_configs(systems.into_configs(), false);
@@ -443,6 +487,17 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        #[cfg(feature = "std")]
+        let start = bevy_platform_support::time::Instant::now();
+
+        self.executable.system_durations.clear();
+        #[cfg(feature = "std")]
+        if self.measure_systems {
+            self.executable
+                .system_durations
+                .resize(self.executable.systems.len(), None);
+        }
+
         let error_handler = self.error_handler.expect("schedule initialized");
 
         #[cfg(not(feature = "bevy_debug_stepping"))]
@@ -463,6 +518,11 @@ impl Schedule {
                 error_handler,
             );
         }
+
+        #[cfg(feature = "std")]
+        {
+            self.last_run_duration = Some(start.elapsed());
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -580,6 +640,63 @@ impl Schedule {
             self.executable.systems.len()
         }
     }
+
+    /// Renders the systems and system sets of this schedule in the [DOT] format,
+    /// for visualization with Graphviz.
+    ///
+    /// Ordering dependencies are drawn as solid edges, set membership as dashed edges from
+    /// each set to its members, and ambiguities as red edges labeled with the conflicting
+    /// components. Ambiguities are only known once the schedule has been initialized.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    #[cfg(feature = "schedule_graphviz")]
+    pub fn to_dot(&self, components: &Components) -> String {
+        use core::fmt::Write;
+
+        let node = |id: &NodeId| match id {
+            NodeId::System(index) => format!("system_{index}"),
+            NodeId::Set(index) => format!("set_{index}"),
+        };
+        let escape = |name: &str| name.replace('\\', "\\\\").replace('"', "\\\"");
+
+        let mut dot = String::from("digraph schedule {\n");
+        let systems = self.graph.systems().map(|(id, system, _)| (id, system));
+        for (id, system) in systems.chain(self.systems().into_iter().flatten()) {
+            let name = escape(&system.name());
+            writeln!(dot, "  {} [label=\"{name}\", shape=box];", node(&id)).unwrap();
+        }
+        for (id, _, _) in self.graph.system_sets() {
+            let name = escape(&self.graph.get_node_name_inner(&id, false));
+            writeln!(dot, "  {} [label=\"{name}\", style=dashed];", node(&id)).unwrap();
+        }
+        for (set, member) in self.graph.hierarchy.graph.all_edges() {
+            writeln!(dot, "  {} -> {} [style=dashed];", node(&set), node(&member)).unwrap();
+        }
+        for (before, after) in self.graph.dependency.graph.all_edges() {
+            writeln!(dot, "  {} -> {};", node(&before), node(&after)).unwrap();
+        }
+        for (a, b, conflicts) in &self.graph.conflicting_systems {
+            let label = if conflicts.is_empty() {
+                "World".into()
+            } else {
+                let names: Vec<_> = conflicts
+                    .iter()
+                    .map(|id| components.get_name(*id).unwrap_or("<unknown>"))
+                    .collect();
+                names.join(", ")
+            };
+            writeln!(
+                dot,
+                "  {} -> {} [dir=none, color=red, label=\"{}\"];",
+                node(a),
+                node(b),
+                escape(&label)
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
 }
 
 /// A directed acyclic graph structure.
@@ -1490,6 +1607,7 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            system_durations: Vec::new(),
         }
     }
 
@@ -2091,6 +2209,50 @@ mod tests {
         schedule.run(&mut world);
     }
 
+    #[test]
+    fn records_last_run_duration() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|| std::thread::sleep(core::time::Duration::from_millis(1)));
+
+        assert!(schedule.last_run_duration().is_none());
+        schedule.run(&mut world);
+        assert!(schedule.last_run_duration().unwrap() >= core::time::Duration::from_millis(1));
+    }
+
+    #[test]
+    fn measures_systems() {
+        use crate::schedule::ExecutorKind;
+        use alloc::vec::Vec;
+
+        fn slow() {
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+
+        for executor in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            #[cfg(feature = "multi_threaded")]
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule
+                .set_executor_kind(executor)
+                .add_systems((slow, (|| {}).run_if(|| false)));
+
+            schedule.run(&mut world);
+            assert_eq!(schedule.last_system_durations().count(), 0);
+
+            schedule.set_measure_systems(true);
+            schedule.run(&mut world);
+            let durations: Vec<_> = schedule.last_system_durations().collect();
+            assert_eq!(durations.len(), 1, "{executor:?}");
+            assert!(durations[0].0.ends_with("slow"));
+            assert!(durations[0].1 >= core::time::Duration::from_millis(1));
+        }
+    }
+
     #[test]
     fn inserts_a_sync_point() {
         let mut schedule = Schedule::default();
@@ -2806,4 +2968,27 @@ mod tests {
             .expect("CheckSystemRan Resource Should Exist");
         assert_eq!(value.0, 2);
     }
+
+    #[cfg(feature = "schedule_graphviz")]
+    #[test]
+    fn schedule_to_dot() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn first() {}
+        fn second() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((first, second.after(first)).in_set(Set));
+        schedule.initialize(&mut world).unwrap();
+
+        let dot = schedule.to_dot(world.components());
+        assert!(dot.starts_with("digraph schedule {"));
+        assert!(dot.contains("first\""));
+        assert!(dot.contains("second\""));
+        assert!(dot.contains("[label=\"Set\""));
+        assert!(dot.contains("[style=dashed];"));
+        assert!(dot.ends_with("}\n"));
+    }
 }
@@ -0,0 +1,125 @@
+//! Small functions and systems to adapt the output of a system,
+//! for use with [`IntoSystem::map`] and [`IntoSystem::pipe`].
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::system::adapter;
+//!
+//! #[derive(Event)]
+//! struct LevelUp(u32);
+//!
+//! fn parse_config() -> Result<(), core::num::ParseIntError> {
+//!     "not a number".parse::<u32>()?;
+//!     Ok(())
+//! }
+//!
+//! fn check_experience() -> Option<LevelUp> {
+//!     None
+//! }
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems((
+//!     // Logs the error instead of requiring a handler system.
+//!     parse_config.map(adapter::warn),
+//!     // Sends the event if there is one.
+//!     check_experience.pipe(adapter::send_events),
+//! ));
+//! # let mut world = World::new();
+//! # world.init_resource::<Events<LevelUp>>();
+//! # schedule.run(&mut world);
+//! ```
+//!
+//! [`IntoSystem::map`]: super::IntoSystem::map
+//! [`IntoSystem::pipe`]: super::IntoSystem::pipe
+
+use crate::{
+    event::{Event, EventWriter},
+    system::In,
+};
+use core::fmt::Debug;
+
+/// Discards the output of a system.
+pub fn ignore<T>(_: T) {}
+
+/// Unwraps the output of a system, panicking with the error if there is one.
+#[track_caller]
+pub fn unwrap<T, E: Debug>(result: Result<T, E>) -> T {
+    result.unwrap()
+}
+
+/// Logs the output of a system with [`log::info!`].
+pub fn info<T: Debug>(value: T) {
+    log::info!("{value:?}");
+}
+
+/// Logs the output of a system with [`log::debug!`].
+pub fn dbg<T: Debug>(value: T) {
+    log::debug!("{value:?}");
+}
+
+/// Logs the error returned by a system with [`log::warn!`], if any.
+pub fn warn<E: Debug>(result: Result<(), E>) {
+    if let Err(error) = result {
+        log::warn!("{error:?}");
+    }
+}
+
+/// Logs the error returned by a system with [`log::error!`], if any.
+pub fn error<E: Debug>(result: Result<(), E>) {
+    if let Err(error) = result {
+        log::error!("{error:?}");
+    }
+}
+
+/// A system that sends the events returned by another system, to be used with
+/// [`IntoSystem::pipe`](super::IntoSystem::pipe).
+///
+/// Any [`IntoIterator`] of events can be piped in, such as an [`Option`] to send an event
+/// only sometimes, or a [`Vec`](alloc::vec::Vec) to send several.
+pub fn send_events<E, I>(In(events): In<I>, mut writer: EventWriter<E>)
+where
+    E: Event,
+    I: IntoIterator<Item = E> + 'static,
+{
+    writer.write_batch(events);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::Events,
+        prelude::{Schedule, World},
+        system::IntoSystem,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Event, Debug, PartialEq)]
+    struct Value(u32);
+
+    #[test]
+    fn adapters() {
+        fn fail() -> Result<(), &'static str> {
+            Err("failed")
+        }
+        fn values() -> Vec<Value> {
+            vec![Value(1), Value(2)]
+        }
+        fn nothing() -> Option<Value> {
+            None
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Events<Value>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            fail.map(warn),
+            values.pipe(send_events),
+            nothing.pipe(send_events),
+        ));
+        schedule.run(&mut world);
+
+        let events: Vec<_> = world.resource_mut::<Events<Value>>().drain().collect();
+        assert_eq!(events, vec![Value(1), Value(2)]);
+    }
+}
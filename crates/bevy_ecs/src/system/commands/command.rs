@@ -13,7 +13,10 @@ use crate::{
     resource::Resource,
     result::{Error, Result},
     schedule::ScheduleLabel,
-    system::{error_handler, IntoSystem, SystemId, SystemInput},
+    system::{
+        error_handler, BoxedSystem, IntoSystem, RegisteredSystemError, RunSystemError, SystemId,
+        SystemInput,
+    },
     world::{FromWorld, SpawnBatchIter, World},
 };
 
@@ -71,11 +74,13 @@ pub trait HandleError<Out = ()> {
     fn handle_error_with(self, error_handler: fn(&mut World, Error)) -> impl Command;
     /// Takes a [`Command`] that returns a Result and uses the default error handler function to convert it into
     /// a [`Command`] that internally handles an error if it occurs and returns `()`.
+    ///
+    /// The world's [`CommandErrorHandler`](error_handler::CommandErrorHandler) is used instead if present.
     fn handle_error(self) -> impl Command
     where
         Self: Sized,
     {
-        self.handle_error_with(error_handler::default())
+        self.handle_error_with(error_handler::world_default())
     }
 }
 
@@ -169,21 +174,42 @@ pub fn remove_resource<R: Resource>() -> impl Command {
 }
 
 /// A [`Command`] that runs the system corresponding to the given [`SystemId`].
+///
+/// The system is skipped without an error if its run condition is not met.
 pub fn run_system<O: 'static>(id: SystemId<(), O>) -> impl Command<Result> {
     move |world: &mut World| -> Result {
-        world.run_system(id)?;
-        Ok(())
+        match world.run_system(id) {
+            Ok(_) | Err(RegisteredSystemError::ConditionNotMet(_)) => Ok(()),
+            Err(error) => Err(error.into()),
+        }
     }
 }
 
 /// A [`Command`] that runs the system corresponding to the given [`SystemId`]
 /// and provides the given input value.
-pub fn run_system_with<I>(id: SystemId<I>, input: I::Inner<'static>) -> impl Command<Result>
+///
+/// The system is skipped without an error if its run condition is not met.
+pub fn run_system_with<I, O>(id: SystemId<I, O>, input: I::Inner<'static>) -> impl Command<Result>
 where
     I: SystemInput<Inner<'static>: Send> + 'static,
+    O: 'static,
 {
     move |world: &mut World| -> Result {
-        world.run_system_with(id, input)?;
+        match world.run_system_with(id, input) {
+            Ok(_) | Err(RegisteredSystemError::ConditionNotMet(_)) => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+/// A [`Command`] that initializes and runs the given boxed system once, without registering it.
+pub fn run_boxed_system<O: 'static>(mut system: BoxedSystem<(), O>) -> impl Command<Result> {
+    move |world: &mut World| -> Result {
+        system.initialize(world);
+        if !system.validate_param(world) {
+            return Err(RunSystemError::InvalidParams(system.name()).into());
+        }
+        system.run((), world);
         Ok(())
     }
 }
@@ -4,19 +4,19 @@
 //! It also contains functions that return closures for use with
 //! [`EntityCommands`](crate::system::EntityCommands).
 
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use core::fmt;
 use log::info;
 
 use crate::{
-    bundle::{Bundle, InsertMode},
+    bundle::{Bundle, BundleFromComponents, InsertMode},
     change_detection::MaybeLocation,
     component::{Component, ComponentId, ComponentInfo},
     entity::{Entity, EntityClonerBuilder},
     event::Event,
     relationship::RelationshipInsertHookMode,
     result::Result,
-    system::{command::HandleError, Command, IntoObserverSystem},
+    system::{command::HandleError, Callback, Command, IntoObserverSystem, RegisteredSystemError},
     world::{error::EntityMutableFetchError, EntityWorldMut, FromWorld, World},
 };
 use bevy_ptr::OwningPtr;
@@ -288,6 +288,22 @@ pub fn trigger(event: impl Event) -> impl EntityCommand {
     }
 }
 
+/// An [`EntityCommand`] that runs the [`Callback`] of an entity, with the entity as input.
+///
+/// The callback is skipped without an error if its run condition is not met.
+pub fn trigger_callback() -> impl EntityCommand<Result> {
+    move |mut entity: EntityWorldMut| -> Result {
+        let id = entity.id();
+        let Callback(callback) = *entity
+            .get::<Callback>()
+            .ok_or_else(|| format!("Entity {id} has no Callback component"))?;
+        entity.world_scope(|world| match world.run_system_with(callback, id) {
+            Ok(()) | Err(RegisteredSystemError::ConditionNotMet(_)) => Ok(()),
+            Err(error) => Err(error.into()),
+        })
+    }
+}
+
 /// An [`EntityCommand`] that clones parts of an entity onto another entity,
 /// configured through [`EntityClonerBuilder`].
 pub fn clone_with(
@@ -315,6 +331,20 @@ pub fn move_components<B: Bundle>(target: Entity) -> impl EntityCommand {
     }
 }
 
+/// An [`EntityCommand`] that removes the components in a [`Bundle`] from an entity
+/// and passes their values to `f`.
+///
+/// Nothing is removed and `f` is not called unless the entity has all of the components.
+pub fn take_with<T: Bundle + BundleFromComponents>(
+    f: impl FnOnce(T, &mut World) + Send + 'static,
+) -> impl EntityCommand {
+    move |mut entity: EntityWorldMut| {
+        if let Some(bundle) = entity.take::<T>() {
+            entity.world_scope(|world| f(bundle, world));
+        }
+    }
+}
+
 /// An [`EntityCommand`] that logs the components of an entity.
 pub fn log_components() -> impl EntityCommand {
     move |entity: EntityWorldMut| {
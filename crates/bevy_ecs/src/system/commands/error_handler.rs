@@ -1,7 +1,7 @@
 //! This module contains convenience functions that return simple error handlers
 //! for use with [`Commands::queue_handled`](super::Commands::queue_handled) and [`EntityCommands::queue_handled`](super::EntityCommands::queue_handled).
 
-use crate::{result::Error, world::World};
+use crate::{resource::Resource, result::Error, world::World};
 use log::{error, warn};
 
 /// An error handler that does nothing.
@@ -59,3 +59,32 @@ pub static GLOBAL_ERROR_HANDLER: std::sync::OnceLock<fn(&mut World, Error)> =
 pub fn default() -> fn(&mut World, Error) {
     *GLOBAL_ERROR_HANDLER.get_or_init(|| panic())
 }
+
+/// Overrides the [default error handler](default) for the commands applied to a [`World`].
+///
+/// Unlike `GLOBAL_ERROR_HANDLER`, this is looked up when a failing command is applied, so it can
+/// be inserted or changed at any time and differ between worlds.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::error_handler::{self, CommandErrorHandler};
+/// let mut world = World::new();
+/// // Log failing commands instead of panicking.
+/// world.insert_resource(CommandErrorHandler(error_handler::warn()));
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CommandErrorHandler(pub fn(&mut World, Error));
+
+/// An error handler that forwards the error to the world's [`CommandErrorHandler`],
+/// or to the [default error handler](default) if there is none.
+///
+/// This is used by [`Commands::queue`](super::Commands::queue) and
+/// [`EntityCommands::queue`](super::EntityCommands::queue).
+pub fn world_default() -> fn(&mut World, Error) {
+    |world, error| {
+        let handler = world
+            .get_resource::<CommandErrorHandler>()
+            .map_or_else(default, |handler| handler.0);
+        handler(world, error);
+    }
+}
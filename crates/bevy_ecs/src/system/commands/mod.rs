@@ -17,7 +17,7 @@ use log::error;
 
 use crate::{
     self as bevy_ecs,
-    bundle::{Bundle, InsertMode, NoBundleEffect},
+    bundle::{Bundle, BundleFromComponents, InsertMode, NoBundleEffect},
     change_detection::{MaybeLocation, Mut},
     component::{Component, ComponentId, Mutable},
     entity::{Entities, Entity, EntityClonerBuilder, EntityDoesNotExistError},
@@ -27,8 +27,9 @@ use crate::{
     result::Error,
     schedule::ScheduleLabel,
     system::{
-        command::HandleError, entity_command::CommandWithEntity, input::SystemInput, Deferred,
-        IntoObserverSystem, IntoSystem, RegisteredSystem, SystemId,
+        command::HandleError, entity_command::CommandWithEntity, input::SystemInput, BoxedSystem,
+        Deferred, IntoObserverSystem, IntoSystem, RegisteredSystem, RegisteredSystemError,
+        SystemId,
     },
     world::{
         command_queue::RawCommandQueue, unsafe_world_cell::UnsafeWorldCell, CommandQueue,
@@ -98,6 +99,8 @@ use crate::{
 /// The [default error handler](error_handler::default) panics.
 /// It can be configured by enabling the `configurable_error_handler` cargo feature,
 /// then setting the `GLOBAL_ERROR_HANDLER`.
+/// It can also be overridden for a single [`World`] by inserting an
+/// [`error_handler::CommandErrorHandler`] resource.
 ///
 /// Alternatively, you can customize the error handler for a specific command
 /// by calling [`Commands::queue_handled`].
@@ -292,10 +295,12 @@ impl<'w, 's> Commands<'w, 's> {
     /// Take all commands from `other` and append them to `self`, leaving `other` empty
     pub fn append(&mut self, other: &mut CommandQueue) {
         match &mut self.queue {
-            InternalQueue::CommandQueue(queue) => queue.bytes.append(&mut other.bytes),
+            InternalQueue::CommandQueue(queue) => queue.append(other),
             InternalQueue::RawCommandQueue(queue) => {
                 // SAFETY: Pointers in `RawCommandQueue` are never null
                 unsafe { queue.bytes.as_mut() }.append(&mut other.bytes);
+                // SAFETY: Pointers in `RawCommandQueue` are never null
+                unsafe { *queue.len.as_mut() += core::mem::take(&mut other.len) };
             }
         }
     }
@@ -900,9 +905,9 @@ impl<'w, 's> Commands<'w, 's> {
     /// Calls [`World::run_system`](World::run_system).
     ///
     /// There is no way to get the output of a system when run as a command, because the
-    /// execution of the system happens later. To get the output of a system, use
+    /// execution of the system happens later, so it is discarded. To get the output of a system, use
     /// [`World::run_system`] or [`World::run_system_with`] instead of running the system as a command.
-    pub fn run_system(&mut self, id: SystemId) {
+    pub fn run_system<O: 'static>(&mut self, id: SystemId<(), O>) {
         self.queue(command::run_system(id).handle_error_with(error_handler::warn()));
     }
 
@@ -913,15 +918,59 @@ impl<'w, 's> Commands<'w, 's> {
     /// Calls [`World::run_system_with`](World::run_system_with).
     ///
     /// There is no way to get the output of a system when run as a command, because the
-    /// execution of the system happens later. To get the output of a system, use
+    /// execution of the system happens later, so it is discarded. To get the output of a system, use
     /// [`World::run_system`] or [`World::run_system_with`] instead of running the system as a command.
-    pub fn run_system_with<I>(&mut self, id: SystemId<I>, input: I::Inner<'static>)
+    pub fn run_system_with<I, O>(&mut self, id: SystemId<I, O>, input: I::Inner<'static>)
     where
         I: SystemInput<Inner<'static>: Send> + 'static,
+        O: 'static,
     {
         self.queue(command::run_system_with(id, input).handle_error_with(error_handler::warn()));
     }
 
+    /// Runs the system corresponding to the given [`SystemId`], if it is registered.
+    ///
+    /// Unlike [`Self::run_system`], this does nothing if the system has been unregistered,
+    /// which is useful to run optional callbacks. Other errors, such as invalid parameters,
+    /// are passed to the [default error handler](error_handler::world_default).
+    ///
+    /// Use [`World::run_system`] instead to handle the [`RegisteredSystemError`].
+    pub fn try_run_system<O: 'static>(&mut self, id: SystemId<(), O>) {
+        self.queue(move |world: &mut World| -> crate::result::Result {
+            match world.run_system(id) {
+                Ok(_)
+                | Err(
+                    RegisteredSystemError::SystemIdNotRegistered(_)
+                    | RegisteredSystemError::ConditionNotMet(_),
+                ) => Ok(()),
+                Err(error) => Err(error.into()),
+            }
+        });
+    }
+
+    /// Runs the system corresponding to the given [`SystemId`] with the given input value,
+    /// if it is registered.
+    ///
+    /// See [`Self::try_run_system`] for more information.
+    ///
+    /// Use [`World::run_system_with`] instead to handle the [`RegisteredSystemError`].
+    pub fn try_run_system_with<I, O>(&mut self, id: SystemId<I, O>, input: I::Inner<'static>)
+    where
+        I: SystemInput<Inner<'static>: Send> + 'static,
+        O: 'static,
+    {
+        self.queue(move |world: &mut World| -> crate::result::Result {
+            match world.run_system_with(id, input) {
+                Ok(_)
+                | Err(
+                    RegisteredSystemError::SystemIdNotRegistered(_)
+                    | RegisteredSystemError::ConditionNotMet(_),
+                ) => Ok(()),
+                Err(error) => Err(error.into()),
+            }
+        });
+    }
+
     /// Registers a system and returns a [`SystemId`] so it can later be called by [`World::run_system`].
     ///
     /// It's possible to register the same systems more than once, they'll be stored separately.
@@ -975,16 +1024,34 @@ impl<'w, 's> Commands<'w, 's> {
         &mut self,
         system: impl IntoSystem<I, O, M> + 'static,
     ) -> SystemId<I, O>
+    where
+        I: SystemInput + Send + 'static,
+        O: Send + 'static,
+    {
+        self.register_boxed_system(Box::new(IntoSystem::into_system(system)))
+    }
+
+    /// Similar to [`Self::register_system`], but allows passing in a [`BoxedSystem`].
+    ///
+    /// This is useful for systems built at runtime, for example by a scripting layer.
+    pub fn register_boxed_system<I, O>(&mut self, system: BoxedSystem<I, O>) -> SystemId<I, O>
     where
         I: SystemInput + Send + 'static,
         O: Send + 'static,
     {
         let entity = self.spawn_empty().id();
-        let system = RegisteredSystem::<I, O>::new(Box::new(IntoSystem::into_system(system)));
-        self.entity(entity).insert(system);
+        self.entity(entity).insert(RegisteredSystem::new(system));
         SystemId::from_entity(entity)
     }
 
+    /// Initializes and runs the given boxed system once, without registering it.
+    ///
+    /// This is useful for systems built at runtime that only need to run once. Use
+    /// [`Self::register_boxed_system`] instead to run a system several times, keeping its state.
+    pub fn run_boxed_system<O: 'static>(&mut self, system: BoxedSystem<(), O>) {
+        self.queue(command::run_boxed_system(system).handle_error_with(error_handler::warn()));
+    }
+
     /// Removes a system previously registered with [`Commands::register_system`] or [`World::register_system`].
     ///
     /// See [`World::unregister_system`] for more information.
@@ -1027,6 +1094,22 @@ impl<'w, 's> Commands<'w, 's> {
     /// Similar to [`Self::run_system_with`], but caching the [`SystemId`] in a
     /// [`CachedSystemId`](crate::system::CachedSystemId) resource.
     ///
+    /// This is useful to pass a payload to a callback, instead of storing it in a resource:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource)]
+    /// # struct Health(u32);
+    /// fn apply_damage(In(damage): In<u32>, mut health: ResMut<Health>) {
+    ///     health.0 = health.0.saturating_sub(damage);
+    /// }
+    ///
+    /// fn on_hit(mut commands: Commands) {
+    ///     commands.run_system_cached_with(apply_damage, 25);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(on_hit);
+    /// ```
+    ///
     /// See [`World::register_system_cached`] for more information.
     pub fn run_system_cached_with<I, M, S>(&mut self, system: S, input: I::Inner<'static>)
     where
@@ -1162,6 +1245,8 @@ impl<'w, 's> Commands<'w, 's> {
 /// The [default error handler](error_handler::default) panics.
 /// It can be configured by enabling the `configurable_error_handler` cargo feature,
 /// then setting the `GLOBAL_ERROR_HANDLER`.
+/// It can also be overridden for a single [`World`] by inserting an
+/// [`error_handler::CommandErrorHandler`] resource.
 ///
 /// Alternatively, you can customize the error handler for a specific command
 /// by calling [`EntityCommands::queue_handled`].
@@ -1923,6 +2008,13 @@ impl<'a> EntityCommands<'a> {
         self.queue(entity_command::trigger(event))
     }
 
+    /// Runs the [`Callback`](crate::system::Callback) of the entity, with the entity as input.
+    ///
+    /// A warning is logged if the entity has no callback or the callback can't be run.
+    pub fn trigger_callback(&mut self) -> &mut Self {
+        self.queue_handled(entity_command::trigger_callback(), error_handler::warn())
+    }
+
     /// Creates an [`Observer`] listening for events of type `E` targeting this entity.
     pub fn observe<E: Event, B: Bundle, M>(
         &mut self,
@@ -2084,6 +2176,31 @@ impl<'a> EntityCommands<'a> {
     pub fn move_components<B: Bundle>(&mut self, target: Entity) -> &mut Self {
         self.queue(entity_command::move_components::<B>(target))
     }
+
+    /// Removes the components in a [`Bundle`] from this entity and passes their values to `f`,
+    /// without requiring them to be [`Clone`].
+    ///
+    /// Nothing is removed and `f` is not called unless the entity has all of the components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Weapon(String);
+    ///
+    /// fn hand_over(mut commands: Commands, giver: Entity, receiver: Entity) {
+    ///     commands.entity(giver).take_with(move |weapon: Weapon, world| {
+    ///         world.entity_mut(receiver).insert(weapon);
+    ///     });
+    /// }
+    /// ```
+    pub fn take_with<T: Bundle + BundleFromComponents>(
+        &mut self,
+        f: impl FnOnce(T, &mut World) + Send + 'static,
+    ) -> &mut Self {
+        self.queue(entity_command::take_with(f))
+    }
 }
 
 /// A wrapper around [`EntityCommands`] with convenience methods for working with a specified component type.
@@ -2222,6 +2339,7 @@ impl<'a, T: Component> EntityEntryCommands<'a, T> {
 #[cfg(test)]
 mod tests {
     use crate::{
+        change_detection::ResMut,
         component::Component,
         resource::Resource,
         system::Commands,
@@ -2579,6 +2697,88 @@ mod tests {
         assert!(world.get_entity(id.entity).is_err());
     }
 
+    #[test]
+    fn try_run_system() {
+        #[derive(Resource, Default)]
+        struct Counter(u8);
+
+        let mut world = World::default();
+        world.init_resource::<Counter>();
+        let id = world.register_system(|mut counter: ResMut<Counter>| counter.0 += 1);
+        let removed = world.register_system(|| {});
+        world.unregister_system(removed).unwrap();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.try_run_system(id);
+        commands.try_run_system(removed);
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // Errors other than an unregistered system are still handled.
+        use crate::system::{error_handler::CommandErrorHandler, Res};
+
+        #[derive(Resource)]
+        struct Missing;
+
+        world.insert_resource(CommandErrorHandler(|world, _| {
+            world.resource_mut::<Counter>().0 += 10;
+        }));
+        let invalid = world.register_system(|_: Res<Missing>| {});
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.try_run_system(invalid);
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 11);
+    }
+
+    #[test]
+    fn boxed_systems() {
+        use crate::system::{BoxedSystem, IntoSystem};
+        use alloc::boxed::Box;
+
+        #[derive(Resource, Default)]
+        struct Counter(u8);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let boxed = || -> BoxedSystem { Box::new(IntoSystem::into_system(increment)) };
+
+        let mut world = World::default();
+        world.init_resource::<Counter>();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let id = commands.register_boxed_system(boxed());
+        commands.run_boxed_system(boxed());
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        world.run_system(id).unwrap();
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
+    #[test]
+    fn run_system_cached_with_input() {
+        use crate::system::In;
+
+        #[derive(Resource)]
+        struct Health(u32);
+
+        fn apply_damage(In(damage): In<u32>, mut health: ResMut<Health>) {
+            health.0 -= damage;
+        }
+
+        let mut world = World::default();
+        world.insert_resource(Health(100));
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.run_system_cached_with(apply_damage, 25);
+        commands.run_system_cached_with(apply_damage, 10);
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<Health>().0, 65);
+    }
+
     fn is_send<T: Send>() {}
     fn is_sync<T: Sync>() {}
 
@@ -2606,4 +2806,47 @@ mod tests {
         assert!(world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    fn command_error_handler() {
+        #[derive(Resource, Default)]
+        struct Errors(usize);
+
+        let mut world = World::default();
+        world.init_resource::<Errors>();
+        world.insert_resource(super::error_handler::CommandErrorHandler(|world, _| {
+            world.resource_mut::<Errors>().0 += 1;
+        }));
+
+        let entity = world.spawn_empty().id();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.queue(|_: &mut World| -> crate::result::Result { Err("failed".into()) });
+        commands.entity(entity).despawn();
+        commands.entity(entity).insert(W(0u32));
+        queue.apply(&mut world);
+
+        assert_eq!(world.resource::<Errors>().0, 2);
+    }
+
+    #[test]
+    fn take_with() {
+        // Deliberately not `Clone`.
+        #[derive(Component, PartialEq, Debug)]
+        struct Item(String);
+
+        let mut world = World::default();
+        let giver = world.spawn(Item("sword".into())).id();
+        let receiver = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(giver).take_with(move |item: Item, world| {
+            world.entity_mut(receiver).insert(item);
+        });
+        queue.apply(&mut world);
+
+        assert!(!world.entity(giver).contains::<Item>());
+        assert_eq!(world.get::<Item>(receiver), Some(&Item("sword".into())));
+    }
 }
@@ -119,6 +119,7 @@
 //!
 //! [`Vec<P>`]: alloc::vec::Vec
 
+pub mod adapter;
 mod adapter_system;
 mod builder;
 mod combinator;
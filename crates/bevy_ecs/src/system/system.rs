@@ -187,6 +187,36 @@ pub unsafe trait ReadOnlySystem: System {
     ///
     /// Unlike [`System::run`], this can be called with a shared reference to the world,
     /// since this system is known not to modify the world.
+    ///
+    /// The system must have been [initialized](System::initialize) with the same world first,
+    /// which requires mutable access. Initialize it up front to run inspection or debug code
+    /// from places that only have a `&World`, such as [`World::resource_scope`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::ReadOnlySystem;
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Log(Vec<usize>);
+    ///
+    /// fn count_enemies(enemies: Query<(), With<Enemy>>) -> usize {
+    ///     enemies.iter().count()
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.spawn(Enemy);
+    /// world.init_resource::<Log>();
+    ///
+    /// let mut count_enemies = IntoSystem::into_system(count_enemies);
+    /// count_enemies.initialize(&mut world);
+    ///
+    /// world.resource_scope(|world, mut log: Mut<Log>| {
+    ///     log.0.push(count_enemies.run_readonly((), world));
+    /// });
+    /// assert_eq!(world.resource::<Log>().0, vec![1]);
+    /// ```
     fn run_readonly(&mut self, input: SystemIn<'_, Self>, world: &World) -> Self::Out {
         let world = world.as_unsafe_world_cell_readonly();
         self.update_archetype_component_access(world);
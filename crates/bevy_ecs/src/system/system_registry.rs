@@ -2,34 +2,189 @@
 use crate::reflect::ReflectComponent;
 use crate::{
     change_detection::Mut,
-    entity::Entity,
-    system::{input::SystemInput, BoxedSystem, IntoSystem},
-    world::World,
+    component::HookContext,
+    entity::{hash_map::EntityHashMap, Entity},
+    query::With,
+    schedule::{BoxedCondition, Condition},
+    system::{input::SystemInput, BoxedSystem, In, IntoSystem},
+    world::{DeferredWorld, World},
 };
-use alloc::boxed::Box;
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use bevy_ecs_macros::{Component, Resource};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use core::marker::PhantomData;
+use core::{any::TypeId, marker::PhantomData, time::Duration};
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
+///
+/// The system is taken out while it runs, so that running it again from within is detected.
 #[derive(Component)]
 #[require(SystemIdMarker)]
+#[component(on_remove = forget_registered_system_runs)]
 pub(crate) struct RegisteredSystem<I, O> {
     initialized: bool,
-    system: BoxedSystem<I, O>,
+    system: Option<BoxedSystem<I, O>>,
 }
 
 impl<I, O> RegisteredSystem<I, O> {
     pub fn new(system: BoxedSystem<I, O>) -> Self {
         RegisteredSystem {
             initialized: false,
-            system,
+            system: Some(system),
         }
     }
 }
 
+/// A run condition attached to a registered system with [`World::register_system_with_condition`].
+///
+/// Like the system, the condition is taken out while it runs.
+#[derive(Component)]
+struct RegisteredSystemCondition {
+    initialized: bool,
+    condition: Option<BoxedCondition>,
+}
+
+/// The type of the system cached on a registered system's entity by
+/// [`World::register_system_cached`].
+///
+/// A [`CachedSystemId`] outlives its entity when entities are cleared, after which the entity
+/// may be reused by another registered system, so the type is checked before the id is reused.
+#[derive(Component)]
+struct CachedSystemOf(TypeId);
+
+/// A callback attached to an entity: a registered system that takes the entity as input.
+///
+/// Run it with [`EntityCommands::trigger_callback`](crate::system::EntityCommands::trigger_callback),
+/// for example to handle a button press without defining an event for it.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, system::Callback};
+/// #[derive(Component)]
+/// struct Clicked(bool);
+///
+/// fn on_click(In(button): In<Entity>, mut clicked: Query<&mut Clicked>) {
+///     clicked.get_mut(button).unwrap().0 = true;
+/// }
+///
+/// let mut world = World::new();
+/// let on_click = world.register_system(on_click);
+/// let button = world.spawn((Clicked(false), Callback(on_click))).id();
+///
+/// world.commands().entity(button).trigger_callback();
+/// world.flush();
+/// assert!(world.get::<Clicked>(button).unwrap().0);
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Callback(pub SystemId<In<Entity>>);
+
+/// Tags a registered system with the name of its owner, usually the plugin that registered it.
+///
+/// Use [`World::register_owned_system`] to register an owned system, and
+/// [`World::unregister_systems_owned_by`] to remove all of an owner's systems at once, for
+/// example when rebuilding a plugin or resetting a test harness.
+#[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SystemOwner(pub Cow<'static, str>);
+
+/// Records how long the systems registered with [`World::register_system`] take to run, and how
+/// many commands they queue.
+///
+/// One-shot systems don't run in a [`Schedule`](crate::schedule::Schedule), so they aren't
+/// covered by its profiling. Insert this resource into the [`World`] to record every run of a
+/// registered system, including runs with [`World::run_systems_parallel`]. The application of
+/// the system's commands isn't measured. Nothing is recorded while the resource is missing, or
+/// without the `std` feature.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, system::RegistryDiagnostics};
+/// let mut world = World::new();
+/// world.init_resource::<RegistryDiagnostics>();
+/// let id = world.register_system(|| {});
+/// world.run_system(id).unwrap();
+///
+/// let runs = world.resource::<RegistryDiagnostics>().get(id).unwrap();
+/// assert_eq!(runs.run_count, 1);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct RegistryDiagnostics {
+    systems: EntityHashMap<RegisteredSystemRuns>,
+}
+
+/// The runs of a registered system, recorded in [`RegistryDiagnostics`].
+#[derive(Debug, Clone)]
+pub struct RegisteredSystemRuns {
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The number of times the system has run.
+    pub run_count: u64,
+    /// The duration of the most recent run.
+    pub last_duration: Duration,
+    /// The total duration of all runs.
+    pub total_duration: Duration,
+    /// The total number of commands queued by all runs.
+    ///
+    /// Exclusive systems apply their commands before returning, so they never queue any.
+    pub commands: u64,
+}
+
+impl RegistryDiagnostics {
+    /// Returns the recorded runs of the system with the given [`SystemId`], if it has run.
+    pub fn get<I, O>(&self, id: SystemId<I, O>) -> Option<&RegisteredSystemRuns> {
+        self.systems.get(&id.entity)
+    }
+
+    /// Returns `true` if the system with the given [`Entity`] has run and is still registered.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.systems.contains_key(&entity)
+    }
+
+    /// Returns an iterator over the recorded runs of all systems that have run, with the
+    /// [`Entity`] of their [`SystemId`].
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &RegisteredSystemRuns)> {
+        self.systems.iter().map(|(entity, runs)| (*entity, runs))
+    }
+
+    /// Forgets the runs of all systems.
+    pub fn clear(&mut self) {
+        self.systems.clear();
+    }
+
+    #[cfg(feature = "std")]
+    fn record(
+        &mut self,
+        entity: Entity,
+        name: Cow<'static, str>,
+        duration: Duration,
+        commands: usize,
+    ) {
+        let runs = self
+            .systems
+            .entry(entity)
+            .or_insert_with(|| RegisteredSystemRuns {
+                name,
+                run_count: 0,
+                last_duration: Duration::ZERO,
+                total_duration: Duration::ZERO,
+                commands: 0,
+            });
+        runs.run_count += 1;
+        runs.last_duration = duration;
+        runs.total_duration += duration;
+        runs.commands += commands as u64;
+    }
+}
+
+/// Configures how systems registered with [`World::register_system`] are run.
+///
+/// Insert this resource into the [`World`] to change the behavior of [`World::run_system`],
+/// [`World::run_system_with`] and their [`Commands`](crate::system::Commands) counterparts.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemRegistryConfig {
+    /// If `true`, registered systems always run as if with [`World::run_system_deferred`],
+    /// leaving their commands in the world's command queue instead of applying them right away.
+    pub defer_commands: bool,
+}
+
 /// Marker [`Component`](bevy_ecs::component::Component) for identifying [`SystemId`] [`Entity`]s.
 #[derive(Component, Default)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
@@ -62,6 +217,10 @@ impl<I, O> RemovedSystem<I, O> {
 ///
 /// These are opaque identifiers, keyed to a specific [`World`],
 /// and are created via [`World::register_system`].
+///
+/// Each registration gets its own id and state, even when registering the same system twice.
+/// Ids are cheap to copy, so they can be stored in components or resources and used as callbacks
+/// with [`World::run_system`] or [`Commands::run_system`](crate::system::Commands::run_system).
 pub struct SystemId<I: SystemInput = (), O = ()> {
     pub(crate) entity: Entity,
     pub(crate) marker: PhantomData<fn(I) -> O>,
@@ -154,6 +313,26 @@ impl World {
     /// This allows for running systems in a pushed-based fashion.
     /// Using a [`Schedule`](crate::schedule::Schedule) is still preferred for most cases
     /// due to its better performance and ability to run non-conflicting systems simultaneously.
+    ///
+    /// To register the same function several times with different settings, build it with
+    /// initial values for its [`Local`](crate::system::Local)s using a
+    /// [`LocalBuilder`](crate::system::LocalBuilder):
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::LocalBuilder};
+    /// fn heal(amount: Local<u32>) -> u32 {
+    ///     *amount
+    /// }
+    ///
+    /// let mut world = World::default();
+    /// let small_potion = (LocalBuilder(10),).build_state(&mut world).build_system(heal);
+    /// let large_potion = (LocalBuilder(50),).build_state(&mut world).build_system(heal);
+    /// let small_potion = world.register_system(small_potion);
+    /// let large_potion = world.register_system(large_potion);
+    ///
+    /// assert_eq!(world.run_system(small_potion).unwrap(), 10);
+    /// assert_eq!(world.run_system(large_potion).unwrap(), 50);
+    /// ```
     pub fn register_system<I, O, M>(
         &mut self,
         system: impl IntoSystem<I, O, M> + 'static,
@@ -178,10 +357,100 @@ impl World {
         SystemId::from_entity(entity)
     }
 
+    /// Registers a system with a [run condition](Condition) and returns a [`SystemId`] so it can
+    /// later be called by [`World::run_system`].
+    ///
+    /// Every time the system is run by its id, the condition is evaluated first. If it returns
+    /// `false`, the system is skipped and [`RegisteredSystemError::ConditionNotMet`] is returned,
+    /// which [`Commands::run_system`](crate::system::Commands::run_system) silently ignores.
+    /// This lets callbacks respect the state of the game without checking it themselves.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::RegisteredSystemError;
+    /// #[derive(Resource)]
+    /// struct Paused(bool);
+    ///
+    /// let mut world = World::default();
+    /// world.insert_resource(Paused(true));
+    /// let id = world.register_system_with_condition(
+    ///     || println!("playing"),
+    ///     |paused: Res<Paused>| !paused.0,
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     world.run_system(id),
+    ///     Err(RegisteredSystemError::ConditionNotMet(_))
+    /// ));
+    /// world.resource_mut::<Paused>().0 = false;
+    /// assert!(world.run_system(id).is_ok());
+    /// ```
+    pub fn register_system_with_condition<I, O, M, C>(
+        &mut self,
+        system: impl IntoSystem<I, O, M> + 'static,
+        condition: impl Condition<C>,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.register_system(system);
+        let condition: BoxedCondition = Box::new(IntoSystem::into_system(condition));
+        self.entity_mut(id.entity)
+            .insert(RegisteredSystemCondition {
+                initialized: false,
+                condition: Some(condition),
+            });
+        id
+    }
+
+    /// Registers a system owned by `owner`, tagging it with a [`SystemOwner`] component.
+    ///
+    /// All systems of an owner can later be removed with [`World::unregister_systems_owned_by`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// let mut world = World::default();
+    /// let id = world.register_owned_system("my_plugin", || println!("callback"));
+    /// world.unregister_systems_owned_by("my_plugin");
+    /// assert!(world.run_system(id).is_err());
+    /// ```
+    pub fn register_owned_system<I, O, M>(
+        &mut self,
+        owner: impl Into<Cow<'static, str>>,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.register_system(system);
+        self.entity_mut(id.entity).insert(SystemOwner(owner.into()));
+        id
+    }
+
+    /// Removes all registered systems tagged with the given [`SystemOwner`].
+    ///
+    /// Their [`SystemId`]s become invalid, and running them afterwards will result in errors.
+    pub fn unregister_systems_owned_by(&mut self, owner: &str) {
+        let entities: Vec<Entity> = self
+            .query::<(Entity, &SystemOwner)>()
+            .iter(self)
+            .filter(|(_, system_owner)| system_owner.0 == owner)
+            .map(|(entity, _)| entity)
+            .collect();
+        for entity in entities {
+            self.despawn(entity);
+        }
+    }
+
     /// Removes a registered system and returns the system, if it exists.
     /// After removing a system, the [`SystemId`] becomes invalid and attempting to use it afterwards will result in errors.
     /// Re-adding the removed system will register it on a new [`SystemId`].
     ///
+    /// The returned [`RemovedSystem`] keeps the system's state, so it can be inspected or
+    /// registered again with [`World::register_boxed_system`].
+    ///
     /// If no system corresponds to the given [`SystemId`], this method returns an error.
     /// Systems are also not allowed to remove themselves, this returns an error too.
     pub fn unregister_system<I, O>(
@@ -193,20 +462,98 @@ impl World {
         O: 'static,
     {
         match self.get_entity_mut(id.entity) {
+            Ok(entity) if !entity.contains::<SystemIdMarker>() => {
+                Err(RegisteredSystemError::SystemIdNotRegistered(id))
+            }
             Ok(mut entity) => {
-                let registered_system = entity
+                // The system is missing while it is running.
+                if !entity
+                    .get::<RegisteredSystem<I, O>>()
+                    .is_some_and(|registered_system| registered_system.system.is_some())
+                {
+                    return Err(RegisteredSystemError::SelfRemove(id));
+                }
+                let removed_system = entity
                     .take::<RegisteredSystem<I, O>>()
+                    .and_then(|registered_system| {
+                        Some(RemovedSystem {
+                            initialized: registered_system.initialized,
+                            system: registered_system.system?,
+                        })
+                    })
                     .ok_or(RegisteredSystemError::SelfRemove(id))?;
                 entity.despawn();
-                Ok(RemovedSystem {
-                    initialized: registered_system.initialized,
-                    system: registered_system.system,
-                })
+                Ok(removed_system)
             }
             Err(_) => Err(RegisteredSystemError::SystemIdNotRegistered(id)),
         }
     }
 
+    /// Replaces a registered system with a new one, keeping its [`SystemId`] and run condition,
+    /// and returns the previous system.
+    ///
+    /// The new system starts from a fresh state, so passing a new instance of the same system
+    /// resets its [`Local`](crate::system::Local)s and change detection, for example when
+    /// restarting a level.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// fn count(mut counter: Local<u8>) -> u8 {
+    ///     *counter += 1;
+    ///     *counter
+    /// }
+    ///
+    /// let mut world = World::default();
+    /// let id = world.register_system(count);
+    /// world.run_system(id).unwrap();
+    /// assert_eq!(world.run_system(id).unwrap(), 2);
+    ///
+    /// world.replace_system(id, count).unwrap();
+    /// assert_eq!(world.run_system(id).unwrap(), 1);
+    /// ```
+    ///
+    /// If no system corresponds to the given [`SystemId`], this method returns an error.
+    /// Systems are also not allowed to replace themselves, this returns an error too.
+    pub fn replace_system<I, O, M>(
+        &mut self,
+        id: SystemId<I, O>,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> Result<RemovedSystem<I, O>, RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let mut registered_system = self
+            .get_entity_mut(id.entity)
+            .ok()
+            .and_then(|entity| entity.into_mut::<RegisteredSystem<I, O>>())
+            .ok_or(RegisteredSystemError::SystemIdNotRegistered(id))?;
+        let Some(previous) = registered_system.system.take() else {
+            return Err(RegisteredSystemError::SelfRemove(id));
+        };
+        let removed_system = RemovedSystem {
+            initialized: registered_system.initialized,
+            system: previous,
+        };
+        registered_system.initialized = false;
+        registered_system.system = Some(Box::new(IntoSystem::into_system(system)));
+        Ok(removed_system)
+    }
+
+    /// Removes all registered systems, including cached ones.
+    ///
+    /// All [`SystemId`]s become invalid, and running them afterwards will result in errors.
+    /// Cached systems are registered again the next time they are run.
+    pub fn unregister_all_systems(&mut self) {
+        let entities: Vec<Entity> = self
+            .query_filtered::<Entity, With<SystemIdMarker>>()
+            .iter(self)
+            .collect();
+        for entity in entities {
+            self.despawn(entity);
+        }
+    }
+
     /// Run stored systems by their [`SystemId`].
     /// Before running a system, it must first be registered.
     /// The method [`World::register_system`] stores a given system and returns a [`SystemId`].
@@ -215,9 +562,15 @@ impl World {
     ///
     /// In order to run a chained system with an input, use [`World::run_system_with`] instead.
     ///
+    /// Systems combined with [`pipe`](crate::system::IntoSystem::pipe) can be registered and run
+    /// like any other system, as a single [`SystemId`].
+    ///
     /// # Limitations
     ///
-    ///  - Stored systems cannot be recursive, they cannot call themselves through [`Commands::run_system`](crate::system::Commands).
+    ///  - Stored systems cannot run themselves directly through [`World::run_system`], but they can
+    ///    through [`Commands::run_system`](crate::system::Commands), since commands are applied after
+    ///    the system has returned. This does not apply to exclusive systems, which apply their
+    ///    commands before returning.
     ///
     /// # Examples
     ///
@@ -307,7 +660,10 @@ impl World {
     ///
     /// # Limitations
     ///
-    ///  - Stored systems cannot be recursive, they cannot call themselves through [`Commands::run_system`](crate::system::Commands).
+    ///  - Stored systems cannot run themselves directly through [`World::run_system`], but they can
+    ///    through [`Commands::run_system`](crate::system::Commands), since commands are applied after
+    ///    the system has returned. This does not apply to exclusive systems, which apply their
+    ///    commands before returning.
     ///
     /// # Examples
     ///
@@ -332,6 +688,403 @@ impl World {
         id: SystemId<I, O>,
         input: I::Inner<'_>,
     ) -> Result<O, RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let defer = self
+            .get_resource::<SystemRegistryConfig>()
+            .is_some_and(|config| config.defer_commands);
+        self.run_registered_system(id, input, defer)
+    }
+
+    /// Run a stored system by its [`SystemId`] without applying its commands.
+    ///
+    /// Unlike [`World::run_system`], the [`Commands`](crate::system::Commands) and other
+    /// [`Deferred`](crate::system::Deferred) buffers of the system are queued into the world's
+    /// command queue. They are applied the next time the world is flushed, for example at the next
+    /// sync point of the running schedule, instead of moving entities between archetypes in the
+    /// middle of the caller's work. Exclusive systems are not affected, as they don't buffer
+    /// their changes.
+    ///
+    /// To run all registered systems like this, insert a [`SystemRegistryConfig`] resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Spawned;
+    ///
+    /// let mut world = World::default();
+    /// let id = world.register_system(|mut commands: Commands| commands.insert_resource(Spawned));
+    ///
+    /// world.run_system_deferred(id).unwrap();
+    /// assert!(!world.contains_resource::<Spawned>());
+    ///
+    /// world.flush();
+    /// assert!(world.contains_resource::<Spawned>());
+    /// ```
+    pub fn run_system_deferred<O: 'static>(
+        &mut self,
+        id: SystemId<(), O>,
+    ) -> Result<O, RegisteredSystemError<(), O>> {
+        self.run_system_deferred_with(id, ())
+    }
+
+    /// Run a stored system by its [`SystemId`] with an input value, without applying its commands.
+    ///
+    /// See [`World::run_system_deferred`] for more information.
+    pub fn run_system_deferred_with<I, O>(
+        &mut self,
+        id: SystemId<I, O>,
+        input: I::Inner<'_>,
+    ) -> Result<O, RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        self.run_registered_system(id, input, true)
+    }
+
+    /// Runs several stored systems by their [`SystemId`]s, running systems that don't conflict
+    /// with each other in parallel on the [`ComputeTaskPool`](bevy_tasks::ComputeTaskPool).
+    ///
+    /// The systems are split into batches in the given order: a system joins the current batch
+    /// if its data access doesn't conflict with any system in it, and starts a new batch
+    /// otherwise. Exclusive systems and systems accessing non-send data always run on their own
+    /// on the calling thread, so conflicting systems run one after the other, just like calling
+    /// [`World::run_system`] for each of them.
+    ///
+    /// The run condition of a system is evaluated, and the system taken out of the registry, when
+    /// it joins a batch. Once the systems of a batch have run, they are returned to the registry
+    /// and their commands are applied in the given order, before the next batch starts. This
+    /// means that the commands of a system can run any of the given systems again, including
+    /// the other systems of its batch, and that a system given several times runs several times.
+    /// If a [`SystemRegistryConfig`] asks to defer commands, they are left in the world's command
+    /// queue instead.
+    ///
+    /// Without the `multi_threaded` feature, or if the
+    /// [`ComputeTaskPool`](bevy_tasks::ComputeTaskPool) hasn't been initialized, the systems of a
+    /// batch run one after the other.
+    ///
+    /// Returns the result of each system, in the given order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Saves(u32);
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Settings(u32);
+    ///
+    /// let mut world = World::default();
+    /// world.init_resource::<Saves>();
+    /// world.init_resource::<Settings>();
+    ///
+    /// // These two systems don't conflict and run in parallel.
+    /// let on_save = [
+    ///     world.register_system(|mut saves: ResMut<Saves>| saves.0 += 1),
+    ///     world.register_system(|mut settings: ResMut<Settings>| settings.0 += 1),
+    /// ];
+    /// for result in world.run_systems_parallel(&on_save) {
+    ///     result.expect("system succeeded");
+    /// }
+    /// assert_eq!(world.resource::<Saves>().0, 1);
+    /// ```
+    pub fn run_systems_parallel(
+        &mut self,
+        ids: &[SystemId],
+    ) -> Vec<Result<(), RegisteredSystemError>> {
+        let mut results: Vec<Result<(), RegisteredSystemError>> =
+            ids.iter().map(|_| Ok(())).collect();
+        let mut batch = Vec::new();
+        for (index, &id) in ids.iter().enumerate() {
+            // Finish the current batch first if the system can't join it, so that the system
+            // sees its changes and is back in the registry if it is part of it.
+            let joins = match self.take_registered_system(id) {
+                Ok(system) => {
+                    let joins = joins_batch(&batch, &system);
+                    self.return_registered_system(id, system);
+                    joins
+                }
+                Err(RegisteredSystemError::Recursive(_)) => false,
+                Err(_) => true,
+            };
+            if !joins {
+                self.run_system_batch(ids, &mut batch, &mut results);
+            }
+
+            if !self.evaluate_registered_condition(id.entity) {
+                results[index] = Err(RegisteredSystemError::ConditionNotMet(id));
+                continue;
+            }
+            match self.take_registered_system(id) {
+                Ok(system) => batch.push((index, system)),
+                Err(error) => results[index] = Err(error),
+            }
+        }
+        self.run_system_batch(ids, &mut batch, &mut results);
+        results
+    }
+
+    /// Runs a batch of systems taken by [`World::run_systems_parallel`], returns them to the
+    /// registry and applies their commands in order, leaving `batch` empty.
+    fn run_system_batch(
+        &mut self,
+        ids: &[SystemId],
+        batch: &mut Vec<(usize, BoxedSystem)>,
+        results: &mut [Result<(), RegisteredSystemError>],
+    ) {
+        if batch.is_empty() {
+            return;
+        }
+        #[cfg(feature = "std")]
+        let measure = self.contains_resource::<RegistryDiagnostics>();
+        #[cfg(not(feature = "std"))]
+        let measure = false;
+        let mut durations: Vec<Option<Duration>> = batch.iter().map(|_| None).collect();
+        let mut command_counts: Vec<usize> = batch.iter().map(|_| 0).collect();
+
+        let exclusive = batch[0].1.is_exclusive();
+        if exclusive {
+            let (index, system) = &mut batch[0];
+            results[*index] = if system.validate_param(self) {
+                durations[0] = timed(measure, || system.run((), self));
+                Ok(())
+            } else {
+                Err(RegisteredSystemError::InvalidParams(ids[*index]))
+            };
+        } else {
+            #[cfg(feature = "multi_threaded")]
+            let len = batch.len();
+            let world = self.as_unsafe_world_cell();
+            for (index, system) in batch.iter_mut() {
+                system.update_archetype_component_access(world);
+                // SAFETY: `update_archetype_component_access` has been called, and the world is
+                // not mutated before the system runs.
+                if !unsafe { system.validate_param_unsafe(world) } {
+                    results[*index] = Err(RegisteredSystemError::InvalidParams(ids[*index]));
+                }
+            }
+            let runnable = batch
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, (index, _))| results[*index].is_ok())
+                .map(|(position, (_, system))| (position, system));
+
+            #[cfg(feature = "multi_threaded")]
+            let runnable = match bevy_tasks::ComputeTaskPool::try_get().filter(|_| len > 1) {
+                Some(task_pool) => {
+                    let measured = task_pool.scope(|scope| {
+                        for (position, system) in runnable {
+                            scope.spawn(async move {
+                                let duration = timed(measure, || {
+                                    // SAFETY:
+                                    // - We have exclusive access to the entire world, and the
+                                    //   systems of a batch don't conflict with each other.
+                                    // - Batches of several systems only contain `Send` systems,
+                                    //   so they can run on other threads.
+                                    // - `update_archetype_component_access` has been called.
+                                    unsafe { system.run_unsafe((), world) };
+                                });
+                                (position, duration)
+                            });
+                        }
+                    });
+                    for (position, duration) in measured {
+                        durations[position] = duration;
+                    }
+                    None
+                }
+                None => Some(runnable),
+            };
+            #[cfg(not(feature = "multi_threaded"))]
+            let runnable = Some(runnable);
+            for (position, system) in runnable.into_iter().flatten() {
+                durations[position] = timed(measure, || {
+                    // SAFETY:
+                    // - We have exclusive access to the entire world.
+                    // - `update_archetype_component_access` has been called.
+                    unsafe { system.run_unsafe((), world) };
+                });
+            }
+
+            // Move the commands to the world's queue, so they are applied once the systems are
+            // back in the registry and can be run again by them.
+            for ((index, system), commands) in batch.iter_mut().zip(&mut command_counts) {
+                if results[*index].is_ok() {
+                    *commands = self.queue_registered_system_buffers(system);
+                }
+            }
+        }
+
+        let mut queued = Vec::new();
+        for (((index, system), duration), commands) in
+            batch.drain(..).zip(durations).zip(command_counts)
+        {
+            #[cfg(feature = "std")]
+            if let Some(duration) = duration {
+                if let Some(mut diagnostics) = self.get_resource_mut::<RegistryDiagnostics>() {
+                    diagnostics.record(ids[index].entity, system.name(), duration, commands);
+                }
+            }
+            #[cfg(not(feature = "std"))]
+            let _ = (duration, commands);
+            self.return_registered_system(ids[index], system);
+            if !exclusive && results[index].is_ok() {
+                queued.push(ids[index]);
+            }
+        }
+
+        let defer = self
+            .get_resource::<SystemRegistryConfig>()
+            .is_some_and(|config| config.defer_commands);
+        if !defer {
+            self.flush();
+            for id in queued {
+                self.apply_registered_system_buffers(id);
+            }
+        }
+    }
+
+    fn run_registered_system<I, O>(
+        &mut self,
+        id: SystemId<I, O>,
+        input: I::Inner<'_>,
+        defer: bool,
+    ) -> Result<O, RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        if !self.evaluate_registered_condition(id.entity) {
+            return Err(RegisteredSystemError::ConditionNotMet(id));
+        }
+        let mut system = self.take_registered_system(id)?;
+
+        #[cfg(feature = "std")]
+        let start = self
+            .contains_resource::<RegistryDiagnostics>()
+            .then(bevy_platform_support::time::Instant::now);
+
+        // run the system
+        let queued = !system.is_exclusive();
+        let mut commands = 0;
+        let result = if !system.validate_param(self) {
+            Err(RegisteredSystemError::InvalidParams(id))
+        } else if queued {
+            let world = self.as_unsafe_world_cell();
+            system.update_archetype_component_access(world);
+            // SAFETY:
+            // - We have exclusive access to the entire world.
+            // - `update_archetype_component_access` has been called.
+            let output = unsafe { system.run_unsafe(input, world) };
+            // Move the commands to the world's queue, so they are applied once the system is back
+            // in the registry and can be run again by its own commands.
+            commands = self.queue_registered_system_buffers(&mut system);
+            Ok(output)
+        } else {
+            Ok(system.run(input, self))
+        };
+
+        #[cfg(feature = "std")]
+        let elapsed = start.map(|start| (system.name(), start.elapsed()));
+
+        // Return the system before applying anything, as its commands may run it again.
+        self.return_registered_system(id, system);
+
+        #[cfg(feature = "std")]
+        if let Some((name, elapsed)) = elapsed {
+            if let Some(mut diagnostics) = self.get_resource_mut::<RegistryDiagnostics>() {
+                diagnostics.record(id.entity, name, elapsed, commands);
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = commands;
+
+        if !defer {
+            self.flush();
+            if queued {
+                self.apply_registered_system_buffers(id);
+            }
+        }
+        result
+    }
+
+    /// Moves the system buffers of a system taken out of the registry to the world's command
+    /// queue, returning the number of commands it queued.
+    fn queue_registered_system_buffers<I, O>(&mut self, system: &mut BoxedSystem<I, O>) -> usize
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
+        let before = unsafe { self.command_queue.len() };
+        system.queue_deferred(DeferredWorld::from(&mut *self));
+        // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
+        let after = unsafe { self.command_queue.len() };
+        after.saturating_sub(before)
+    }
+
+    /// Applies the system buffers of a stored system that can't be moved to the world's command
+    /// queue, if the system is still registered and not running.
+    fn apply_registered_system_buffers<I, O>(&mut self, id: SystemId<I, O>)
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let Some(mut system) = self
+            .get_entity_mut(id.entity)
+            .ok()
+            .and_then(|entity| entity.into_mut::<RegisteredSystem<I, O>>())
+            .and_then(|mut registered_system| registered_system.system.take())
+        else {
+            return;
+        };
+        system.apply_deferred(self);
+        self.return_registered_system(id, system);
+    }
+
+    /// Evaluates the run condition of a stored system, returning `true` if it has none.
+    fn evaluate_registered_condition(&mut self, entity: Entity) -> bool {
+        let Some((initialized, mut condition)) = self
+            .get_entity_mut(entity)
+            .ok()
+            .and_then(|entity| entity.into_mut::<RegisteredSystemCondition>())
+            .and_then(|mut registered_condition| {
+                Some((
+                    registered_condition.initialized,
+                    registered_condition.condition.take()?,
+                ))
+            })
+        else {
+            return true;
+        };
+
+        if !initialized {
+            condition.initialize(self);
+        }
+        let result = condition.validate_param(self) && condition.run((), self);
+
+        if let Some(mut registered_condition) = self
+            .get_entity_mut(entity)
+            .ok()
+            .and_then(|entity| entity.into_mut::<RegisteredSystemCondition>())
+        {
+            registered_condition.initialized = true;
+            registered_condition.condition = Some(condition);
+        }
+        result
+    }
+
+    /// Takes ownership of a stored system trait object and initializes it if necessary.
+    fn take_registered_system<I, O>(
+        &mut self,
+        id: SystemId<I, O>,
+    ) -> Result<BoxedSystem<I, O>, RegisteredSystemError<I, O>>
     where
         I: SystemInput + 'static,
         O: 'static,
@@ -342,33 +1095,36 @@ impl World {
             .map_err(|_| RegisteredSystemError::SystemIdNotRegistered(id))?;
 
         // take ownership of system trait object
-        let RegisteredSystem {
-            mut initialized,
-            mut system,
-        } = entity
-            .take::<RegisteredSystem<I, O>>()
+        let mut registered_system = entity
+            .get_mut::<RegisteredSystem<I, O>>()
+            .ok_or(RegisteredSystemError::SystemIdNotRegistered(id))?;
+        let initialized = registered_system.initialized;
+        let mut system = registered_system
+            .system
+            .take()
             .ok_or(RegisteredSystemError::Recursive(id))?;
 
-        // run the system
         if !initialized {
             system.initialize(self);
-            initialized = true;
         }
+        Ok(system)
+    }
 
-        let result = if system.validate_param(self) {
-            Ok(system.run(input, self))
-        } else {
-            Err(RegisteredSystemError::InvalidParams(id))
-        };
-
-        // return ownership of system trait object (if entity still exists)
-        if let Ok(mut entity) = self.get_entity_mut(id.entity) {
-            entity.insert::<RegisteredSystem<I, O>>(RegisteredSystem {
-                initialized,
-                system,
-            });
+    /// Returns ownership of a system trait object taken with `take_registered_system`,
+    /// if its entity still exists.
+    fn return_registered_system<I, O>(&mut self, id: SystemId<I, O>, system: BoxedSystem<I, O>)
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        if let Some(mut registered_system) = self
+            .get_entity_mut(id.entity)
+            .ok()
+            .and_then(|entity| entity.into_mut::<RegisteredSystem<I, O>>())
+        {
+            registered_system.initialized = true;
+            registered_system.system = Some(system);
         }
-        result
     }
 
     /// Registers a system or returns its cached [`SystemId`].
@@ -405,19 +1161,34 @@ impl World {
 
         if !self.contains_resource::<CachedSystemId<S>>() {
             let id = self.register_system(system);
+            self.entity_mut(id.entity)
+                .insert(CachedSystemOf(TypeId::of::<S>()));
             self.insert_resource(CachedSystemId::<S>::new(id));
             return id;
         }
 
         self.resource_scope(|world, mut id: Mut<CachedSystemId<S>>| {
-            if let Ok(mut entity) = world.get_entity_mut(id.entity) {
-                if !entity.contains::<RegisteredSystem<I, O>>() {
-                    entity.insert(RegisteredSystem::new(Box::new(IntoSystem::into_system(
-                        system,
-                    ))));
+            // After `World::clear_entities`, the cached entity may have been reused for
+            // something else, even another registered system, so check that it still holds
+            // this system.
+            match world.get_entity_mut(id.entity) {
+                Ok(mut entity)
+                    if entity
+                        .get::<CachedSystemOf>()
+                        .is_some_and(|cached| cached.0 == TypeId::of::<S>()) =>
+                {
+                    if !entity.contains::<RegisteredSystem<I, O>>() {
+                        entity.insert(RegisteredSystem::new(Box::new(IntoSystem::into_system(
+                            system,
+                        ))));
+                    }
+                }
+                _ => {
+                    id.entity = world.register_system(system).entity();
+                    world
+                        .entity_mut(id.entity)
+                        .insert(CachedSystemOf(TypeId::of::<S>()));
                 }
-            } else {
-                id.entity = world.register_system(system).entity();
             }
             SystemId::from_entity(id.entity)
         })
@@ -469,6 +1240,40 @@ impl World {
     }
 }
 
+/// Forgets the recorded runs of a registered system once it is removed, however that happens.
+fn forget_registered_system_runs(mut world: DeferredWorld, context: HookContext) {
+    if let Some(mut diagnostics) = world.get_resource_mut::<RegistryDiagnostics>() {
+        diagnostics.systems.remove(&context.entity);
+    }
+}
+
+/// Returns `true` if `system` can run in parallel with the systems of `batch`.
+fn joins_batch(batch: &[(usize, BoxedSystem)], system: &BoxedSystem) -> bool {
+    let parallel = |system: &BoxedSystem| !system.is_exclusive() && system.is_send();
+    batch.is_empty()
+        || (parallel(system)
+            && batch.iter().all(|(_, other)| {
+                parallel(other)
+                    && other
+                        .component_access()
+                        .is_compatible(system.component_access())
+            }))
+}
+
+/// Calls `run`, returning how long it took if `measure` is `true` and time can be measured.
+fn timed(measure: bool, run: impl FnOnce()) -> Option<Duration> {
+    #[cfg(feature = "std")]
+    if measure {
+        let start = bevy_platform_support::time::Instant::now();
+        run();
+        return Some(start.elapsed());
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = measure;
+    run();
+    None
+}
+
 /// An operation with stored systems failed.
 #[derive(Error)]
 pub enum RegisteredSystemError<I: SystemInput = (), O = ()> {
@@ -493,6 +1298,10 @@ pub enum RegisteredSystemError<I: SystemInput = (), O = ()> {
     /// This can occur because the data required by the system was not present in the world.
     #[error("The data required by the system {0:?} was not found in the world and the system did not run due to failed parameter validation.")]
     InvalidParams(SystemId<I, O>),
+    /// A system registered with [`World::register_system_with_condition`] was skipped, because
+    /// its run condition returned `false`.
+    #[error("System {0:?} did not run because its run condition was not met")]
+    ConditionNotMet(SystemId<I, O>),
 }
 
 impl<I: SystemInput, O> core::fmt::Debug for RegisteredSystemError<I, O> {
@@ -505,6 +1314,7 @@ impl<I: SystemInput, O> core::fmt::Debug for RegisteredSystemError<I, O> {
             Self::Recursive(arg0) => f.debug_tuple("Recursive").field(arg0).finish(),
             Self::SelfRemove(arg0) => f.debug_tuple("SelfRemove").field(arg0).finish(),
             Self::InvalidParams(arg0) => f.debug_tuple("InvalidParams").field(arg0).finish(),
+            Self::ConditionNotMet(arg0) => f.debug_tuple("ConditionNotMet").field(arg0).finish(),
         }
     }
 }
@@ -568,6 +1378,59 @@ mod tests {
         assert_eq!(*world.resource::<Counter>(), Counter(8));
     }
 
+    #[test]
+    fn separate_registrations() {
+        fn count(mut runs: Local<u8>) -> u8 {
+            *runs += 1;
+            *runs
+        }
+
+        let mut world = World::new();
+        let first = world.register_system(count);
+        let second = world.register_system(count);
+        // Registering the same system twice gives two distinct ids with their own state.
+        assert_ne!(first, second);
+        assert_eq!(world.run_system(first).unwrap(), 1);
+        assert_eq!(world.run_system(first).unwrap(), 2);
+        assert_eq!(world.run_system(second).unwrap(), 1);
+    }
+
+    #[test]
+    fn unregister_system() {
+        use crate::system::{RegisteredSystemError, SystemId};
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+
+        let id = world.register_system(|mut counter: ResMut<Counter>| counter.0 += 1);
+        world.run_system(id).unwrap();
+
+        let removed = world.unregister_system(id).unwrap();
+        assert!(removed.initialized());
+        assert!(world.get_entity(id.entity()).is_err());
+        assert!(matches!(
+            world.run_system(id),
+            Err(RegisteredSystemError::SystemIdNotRegistered(_))
+        ));
+        assert!(matches!(
+            world.unregister_system(id),
+            Err(RegisteredSystemError::SystemIdNotRegistered(_))
+        ));
+
+        // The removed system can be registered again.
+        let id = world.register_boxed_system(removed.system());
+        world.run_system(id).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+
+        // Entities that aren't systems are left alone.
+        let entity = world.spawn_empty().id();
+        assert!(matches!(
+            world.unregister_system(SystemId::<(), ()>::from_entity(entity)),
+            Err(RegisteredSystemError::SystemIdNotRegistered(_))
+        ));
+        assert!(world.get_entity(entity).is_ok());
+    }
+
     #[test]
     fn input_values() {
         // Verify that a non-Copy, non-Clone type can be passed in.
@@ -703,6 +1566,34 @@ mod tests {
         assert_eq!(*world.resource::<Counter>(), Counter(5));
     }
 
+    #[test]
+    fn nested_systems_with_outputs() {
+        use crate::system::SystemId;
+
+        #[derive(Resource)]
+        struct Damage(SystemId<In<u8>, u8>);
+
+        fn apply_damage(damage: Res<Damage>, mut commands: Commands) {
+            commands.run_system_with(damage.0, 4);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+
+        let damage = world.register_system(|In(amt): In<u8>, mut counter: ResMut<Counter>| {
+            counter.0 += amt;
+            amt * 2
+        });
+        world.insert_resource(Damage(damage));
+
+        // The output is available when running the system directly...
+        assert_eq!(world.run_system_with(damage, 1).unwrap(), 2);
+        // ...and discarded when running it as a command.
+        let apply_damage_id = world.register_system(apply_damage);
+        let _ = world.run_system(apply_damage_id);
+        assert_eq!(*world.resource::<Counter>(), Counter(5));
+    }
+
     #[test]
     fn cached_system() {
         use crate::system::RegisteredSystemError;
@@ -768,6 +1659,27 @@ mod tests {
         assert!(matches!(output, Ok(8)));
     }
 
+    #[test]
+    fn registered_system_adapters() {
+        fn validate(counter: Res<Counter>) -> Option<u8> {
+            (counter.0 < 2).then_some(counter.0 + 1)
+        }
+
+        fn apply(In(next): In<Option<u8>>, mut commands: Commands) {
+            if let Some(next) = next {
+                commands.insert_resource(Counter(next));
+            }
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let id = world.register_system(validate.pipe(apply));
+        for _ in 0..3 {
+            world.run_system(id).unwrap();
+        }
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
     #[test]
     fn cached_system_into_same_system_type() {
         use crate::result::Result;
@@ -863,4 +1775,363 @@ mod tests {
             Err(RegisteredSystemError::InvalidParams(_))
         ));
     }
+
+    #[test]
+    fn unregister_all_systems() {
+        fn nothing() {}
+
+        let mut world = World::new();
+        let id = world.register_system(nothing);
+        let cached = world.register_system_cached(nothing);
+        world.unregister_all_systems();
+
+        assert!(world.run_system(id).is_err());
+        assert!(world.get_entity(cached.entity()).is_err());
+        // Cached systems are registered again.
+        assert!(world.run_system_cached(nothing).is_ok());
+    }
+
+    #[test]
+    fn clear_entities_unregisters_systems() {
+        use crate::system::{RegisteredSystemError, SystemIdMarker};
+
+        fn nothing() {}
+
+        let mut world = World::new();
+        let id = world.register_system(nothing);
+        world.register_system_cached(nothing);
+
+        // Registered systems are entities, so they are removed with all others, and their
+        // entities can be reused.
+        world.clear_entities();
+        let reused = [world.spawn_empty().id(), world.spawn_empty().id()];
+        assert!(matches!(
+            world.run_system(id),
+            Err(RegisteredSystemError::SystemIdNotRegistered(_))
+        ));
+
+        // The cached system is registered again instead of being added to a reused entity.
+        assert!(world.run_system_cached(nothing).is_ok());
+        for entity in reused {
+            assert!(!world.entity(entity).contains::<SystemIdMarker>());
+        }
+    }
+
+    #[test]
+    fn clear_entities_cached_system_reused_by_other_system() {
+        fn cached(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn other(mut counter: ResMut<Counter>) {
+            counter.0 += 10;
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let stale = world.register_system_cached(cached);
+
+        // The entity of the cached system is reused by an unrelated system of the same type.
+        world.clear_entities();
+        let other = world.register_system(other);
+        assert_eq!(other.entity(), stale.entity());
+
+        world.run_system_cached(cached).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+        world.run_system(other).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(11));
+    }
+
+    #[test]
+    fn system_recursion() {
+        use crate::system::SystemId;
+
+        #[derive(Resource)]
+        struct CountToTen(SystemId);
+
+        fn count_to_ten(mut counter: ResMut<Counter>, id: Res<CountToTen>, mut commands: Commands) {
+            counter.0 += 1;
+            if counter.0 < 10 {
+                commands.run_system(id.0);
+            }
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let id = world.register_system(count_to_ten);
+        world.insert_resource(CountToTen(id));
+        world.run_system(id).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(10));
+    }
+
+    #[test]
+    fn run_system_deferred() {
+        use crate::system::SystemRegistryConfig;
+
+        fn increment(mut commands: Commands) {
+            commands.queue(|world: &mut World| world.resource_mut::<Counter>().0 += 1);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let id = world.register_system(increment);
+
+        world.run_system_deferred(id).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(0));
+        world.flush();
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        // The system can run again before its commands are applied.
+        world.insert_resource(SystemRegistryConfig {
+            defer_commands: true,
+        });
+        world.run_system(id).unwrap();
+        world.run_system(id).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+        world.flush();
+        assert_eq!(*world.resource::<Counter>(), Counter(3));
+
+        world.remove_resource::<SystemRegistryConfig>();
+        world.run_system(id).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(4));
+    }
+
+    #[test]
+    fn run_systems_parallel() {
+        use crate::system::RegisteredSystemError;
+
+        #[derive(Resource, Default, PartialEq, Debug)]
+        struct Other(u8);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn increment_other(mut commands: Commands) {
+            commands.queue(|world: &mut World| world.resource_mut::<Other>().0 += 1);
+        }
+
+        fn double(world: &mut World) {
+            world.resource_mut::<Counter>().0 *= 2;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Other>();
+        let increment = world.register_system(increment);
+        let increment_other = world.register_system(increment_other);
+        let double = world.register_system(double);
+        let unregistered = world.register_system(|| {});
+        world.unregister_system(unregistered).unwrap();
+
+        let results = world.run_systems_parallel(&[
+            increment,
+            increment_other,
+            double,
+            increment,
+            unregistered,
+        ]);
+        // The same system runs again once the batch it was in is done.
+        assert!(results[..4].iter().all(Result::is_ok));
+        assert!(matches!(
+            results[4],
+            Err(RegisteredSystemError::SystemIdNotRegistered(_))
+        ));
+        assert_eq!(*world.resource::<Counter>(), Counter(3));
+        assert_eq!(*world.resource::<Other>(), Other(1));
+
+        // The systems are returned to the registry.
+        let results = world.run_systems_parallel(&[increment, double]);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(*world.resource::<Counter>(), Counter(8));
+    }
+
+    #[test]
+    fn run_systems_parallel_commands_run_sibling() {
+        use crate::system::SystemId;
+
+        #[derive(Resource)]
+        struct Sibling(SystemId);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn run_sibling(mut commands: Commands, sibling: Res<Sibling>) {
+            commands.run_system(sibling.0);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let increment = world.register_system(increment);
+        world.insert_resource(Sibling(increment));
+        let run_sibling = world.register_system(run_sibling);
+
+        // Both systems run in the same batch, which is returned to the registry before the
+        // commands of `run_sibling` are applied.
+        let results = world.run_systems_parallel(&[increment, run_sibling]);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn registry_diagnostics_commands() {
+        use crate::system::RegistryDiagnostics;
+
+        fn spawn_two(mut commands: Commands) {
+            commands.spawn_empty();
+            commands.spawn_empty();
+        }
+
+        fn spawn_exclusive(world: &mut World) {
+            world.commands().spawn_empty();
+        }
+
+        let mut world = World::new();
+        world.init_resource::<RegistryDiagnostics>();
+        let spawn_two = world.register_system(spawn_two);
+        let spawn_exclusive = world.register_system(spawn_exclusive);
+
+        world.run_system(spawn_two).unwrap();
+        world.run_system(spawn_exclusive).unwrap();
+        world.run_systems_parallel(&[spawn_two]);
+
+        let diagnostics = world.resource::<RegistryDiagnostics>();
+        assert_eq!(diagnostics.get(spawn_two).unwrap().commands, 4);
+        // Exclusive systems apply their commands before returning.
+        assert_eq!(diagnostics.get(spawn_exclusive).unwrap().commands, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn registry_diagnostics_forget_removed_systems() {
+        use crate::system::RegistryDiagnostics;
+
+        let mut world = World::new();
+        world.init_resource::<RegistryDiagnostics>();
+        let unregistered = world.register_system(|| {});
+        let despawned = world.register_system(|| {});
+        let cleared = world.register_system(|| {});
+        for id in [unregistered, despawned, cleared] {
+            world.run_system(id).unwrap();
+        }
+
+        world.unregister_system(unregistered).unwrap();
+        world.despawn(despawned.entity());
+        assert!(world
+            .resource::<RegistryDiagnostics>()
+            .get(unregistered)
+            .is_none());
+        assert!(world
+            .resource::<RegistryDiagnostics>()
+            .get(despawned)
+            .is_none());
+        assert!(world
+            .resource::<RegistryDiagnostics>()
+            .get(cleared)
+            .is_some());
+
+        world.unregister_all_systems();
+        assert_eq!(world.resource::<RegistryDiagnostics>().iter().count(), 0);
+
+        let id = world.register_system(|| {});
+        world.run_system(id).unwrap();
+        world.clear_entities();
+        assert_eq!(world.resource::<RegistryDiagnostics>().iter().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn run_systems_parallel_diagnostics() {
+        use crate::system::RegistryDiagnostics;
+
+        #[derive(Resource, Default)]
+        struct Other;
+
+        let mut world = World::new();
+        world.init_resource::<RegistryDiagnostics>();
+        world.init_resource::<Counter>();
+        world.init_resource::<Other>();
+        let ids = [
+            world.register_system(|_: ResMut<Counter>| {}),
+            world.register_system(|_: ResMut<Other>| {}),
+            world.register_system(|_: &mut World| {}),
+        ];
+
+        world.run_systems_parallel(&ids);
+        world.run_systems_parallel(&ids[..1]);
+
+        let diagnostics = world.resource::<RegistryDiagnostics>();
+        let run_counts = ids.map(|id| diagnostics.get(id).unwrap().run_count);
+        assert_eq!(run_counts, [2, 1, 1]);
+    }
+
+    #[test]
+    fn run_systems_parallel_non_send() {
+        use crate::system::NonSendMut;
+
+        struct NonSendCounter(u8, core::marker::PhantomData<*const ()>);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn increment_non_send(mut counter: NonSendMut<NonSendCounter>) {
+            counter.0 += 1;
+        }
+
+        #[cfg(feature = "multi_threaded")]
+        bevy_tasks::ComputeTaskPool::get_or_init(bevy_tasks::TaskPool::default);
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.insert_non_send_resource(NonSendCounter(0, core::marker::PhantomData));
+        let increment = world.register_system(increment);
+        let increment_non_send = world.register_system(increment_non_send);
+
+        // Accessing the non-send resource from another thread would panic.
+        let results = world.run_systems_parallel(&[increment, increment_non_send]);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+        assert_eq!(world.non_send_resource::<NonSendCounter>().0, 1);
+    }
+
+    #[test]
+    fn run_system_with_condition() {
+        use crate::system::{RegisteredSystemError, RunSystemOnce, SystemId};
+
+        #[derive(Resource)]
+        struct Enabled(bool);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn run_increment(mut commands: Commands, id: Res<IncrementId>) {
+            commands.run_system(id.0);
+        }
+
+        #[derive(Resource)]
+        struct IncrementId(SystemId);
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        world.insert_resource(Enabled(false));
+        let id = world.register_system_with_condition(increment, |enabled: Res<Enabled>| enabled.0);
+        world.insert_resource(IncrementId(id));
+
+        assert!(matches!(
+            world.run_system(id),
+            Err(RegisteredSystemError::ConditionNotMet(_))
+        ));
+        // Commands skip the system without an error.
+        world.run_system_once(run_increment).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(0));
+
+        world.resource_mut::<Enabled>().0 = true;
+        world.run_system(id).unwrap();
+        world.run_system_once(run_increment).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
 }
@@ -39,6 +39,8 @@ pub struct CommandQueue {
     pub(crate) bytes: Vec<MaybeUninit<u8>>,
     pub(crate) cursor: usize,
     pub(crate) panic_recovery: Vec<MaybeUninit<u8>>,
+    // The number of commands that have been pushed and not yet applied or dropped.
+    pub(crate) len: usize,
 }
 
 /// Wraps pointers to a [`CommandQueue`], used internally to avoid stacked borrow rules when
@@ -48,6 +50,7 @@ pub(crate) struct RawCommandQueue {
     pub(crate) bytes: NonNull<Vec<MaybeUninit<u8>>>,
     pub(crate) cursor: NonNull<usize>,
     pub(crate) panic_recovery: NonNull<Vec<MaybeUninit<u8>>>,
+    pub(crate) len: NonNull<usize>,
 }
 
 // CommandQueue needs to implement Debug manually, rather than deriving it, because the derived impl just prints
@@ -58,6 +61,7 @@ pub(crate) struct RawCommandQueue {
 impl Debug for CommandQueue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("CommandQueue")
+            .field("len", &self.len)
             .field("len_bytes", &self.bytes.len())
             .finish_non_exhaustive()
     }
@@ -98,6 +102,7 @@ impl CommandQueue {
     /// Take all commands from `other` and append them to `self`, leaving `other` empty
     pub fn append(&mut self, other: &mut CommandQueue) {
         self.bytes.append(&mut other.bytes);
+        self.len += core::mem::take(&mut other.len);
     }
 
     /// Returns false if there are any commands in the queue
@@ -106,6 +111,12 @@ impl CommandQueue {
         self.cursor >= self.bytes.len()
     }
 
+    /// Returns the number of commands in the queue.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
     /// Returns a [`RawCommandQueue`] instance sharing the underlying command queue.
     pub(crate) fn get_raw(&mut self) -> RawCommandQueue {
         // SAFETY: self is always valid memory
@@ -114,6 +125,7 @@ impl CommandQueue {
                 bytes: NonNull::new_unchecked(addr_of_mut!(self.bytes)),
                 cursor: NonNull::new_unchecked(addr_of_mut!(self.cursor)),
                 panic_recovery: NonNull::new_unchecked(addr_of_mut!(self.panic_recovery)),
+                len: NonNull::new_unchecked(addr_of_mut!(self.len)),
             }
         }
     }
@@ -128,6 +140,7 @@ impl RawCommandQueue {
                 bytes: NonNull::new_unchecked(Box::into_raw(Box::default())),
                 cursor: NonNull::new_unchecked(Box::into_raw(Box::new(0usize))),
                 panic_recovery: NonNull::new_unchecked(Box::into_raw(Box::default())),
+                len: NonNull::new_unchecked(Box::into_raw(Box::new(0usize))),
             }
         }
     }
@@ -142,6 +155,16 @@ impl RawCommandQueue {
         (unsafe { *self.cursor.as_ref() }) >= (unsafe { self.bytes.as_ref() }).len()
     }
 
+    /// Returns the number of commands in the queue.
+    ///
+    /// # Safety
+    ///
+    /// * Caller ensures that `len` points to valid memory
+    pub unsafe fn len(&self) -> usize {
+        // SAFETY: Pointers are guaranteed to be valid by the caller
+        unsafe { *self.len.as_ref() }
+    }
+
     /// Push a [`Command`] onto the queue.
     ///
     /// # Safety
@@ -210,6 +233,11 @@ impl RawCommandQueue {
         unsafe {
             bytes.set_len(old_len + size_of::<Packed<C>>());
         }
+
+        // SAFETY: There are no outstanding references to self.len
+        unsafe {
+            *self.len.as_mut() += 1;
+        }
     }
 
     /// If `world` is [`Some`], this will apply the queued [commands](`Command`).
@@ -244,6 +272,11 @@ impl RawCommandQueue {
 
             // Advance to the bytes just after `meta`, which represent a type-erased command.
             local_cursor += size_of::<CommandMeta>();
+            // The command is consumed below, whether it is applied, dropped, or panics.
+            // SAFETY: There are no outstanding references to self.len
+            unsafe {
+                *self.len.as_mut() -= 1;
+            }
             // Construct an owned pointer to the command.
             // SAFETY: It is safe to transfer ownership out of `self.bytes`, since the increment of `cursor` above
             // guarantees that nothing stored in the buffer will get observed after this function ends.
@@ -431,6 +464,24 @@ mod test {
         assert_eq!(world.entities().len(), 2);
     }
 
+    #[test]
+    fn test_command_queue_len() {
+        let mut queue = CommandQueue::default();
+        queue.push(SpawnCommand);
+        queue.push(SpawnCommand);
+        assert_eq!(queue.len(), 2);
+
+        let mut other = CommandQueue::default();
+        other.push(SpawnCommand);
+        queue.append(&mut other);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(other.len(), 0);
+
+        let mut world = World::new();
+        queue.apply(&mut world);
+        assert_eq!(queue.len(), 0);
+    }
+
     #[expect(
         dead_code,
         reason = "The inner string is used to ensure that, when the PanicCommand gets pushed to the queue, some data is written to the `bytes` vector."
@@ -31,7 +31,7 @@ pub use identifier::WorldId;
 pub use spawn_batch::*;
 
 use crate::{
-    archetype::{ArchetypeId, ArchetypeRow, Archetypes},
+    archetype::{ArchetypeId, ArchetypeInvariant, ArchetypeRow, Archetypes},
     bundle::{
         Bundle, BundleEffect, BundleInfo, BundleInserter, BundleSpawner, Bundles, InsertMode,
         NoBundleEffect,
@@ -53,7 +53,7 @@ use crate::{
     resource::Resource,
     schedule::{Schedule, ScheduleLabel, Schedules},
     storage::{ResourceData, Storages},
-    system::Commands,
+    system::{Commands, RegistryDiagnostics},
     world::{
         command_queue::RawCommandQueue,
         error::{
@@ -136,6 +136,8 @@ impl Drop for World {
         drop(unsafe { Box::from_raw(self.command_queue.cursor.as_ptr()) });
         // SAFETY: Pointers in internal command queue are only invalidated here
         drop(unsafe { Box::from_raw(self.command_queue.panic_recovery.as_ptr()) });
+        // SAFETY: Pointers in internal command queue are only invalidated here
+        drop(unsafe { Box::from_raw(self.command_queue.len.as_ptr()) });
     }
 }
 
@@ -215,6 +217,37 @@ impl World {
         &self.archetypes
     }
 
+    /// Registers an [`ArchetypeInvariant`] that every archetype of this world must follow.
+    ///
+    /// Invariants are checked whenever a new archetype is created, so inserting or removing
+    /// components in a way that breaks one panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an existing archetype already breaks the invariant.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::{prelude::*, archetype::ArchetypeInvariant};
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// let mut world = World::new();
+    /// let player = world.register_component::<Player>();
+    /// let enemy = world.register_component::<Enemy>();
+    /// world.register_archetype_invariant(ArchetypeInvariant::MutuallyExclusive(player, enemy));
+    ///
+    /// // Panics: an entity can't be both a player and an enemy.
+    /// world.spawn((Player, Enemy));
+    /// ```
+    pub fn register_archetype_invariant(&mut self, invariant: ArchetypeInvariant) {
+        self.archetypes.add_invariant(&self.components, invariant);
+    }
+
     /// Retrieves this world's [`Components`] collection.
     #[inline]
     pub fn components(&self) -> &Components {
@@ -3032,6 +3065,11 @@ impl World {
         self.storages.sparse_sets.clear_entities();
         self.archetypes.clear_entities();
         self.entities.clear();
+        // No hooks run here, so forget the runs of the registered systems that were despawned
+        // before their entities are reused.
+        if let Some(mut diagnostics) = self.get_resource_mut::<RegistryDiagnostics>() {
+            diagnostics.clear();
+        }
     }
 
     /// Clears all resources in this [`World`].
@@ -3608,6 +3646,7 @@ impl<T: Default> FromWorld for T {
 mod tests {
     use super::{FromWorld, World};
     use crate::{
+        archetype::ArchetypeInvariant,
         change_detection::{DetectChangesMut, MaybeLocation},
         component::{ComponentCloneBehavior, ComponentDescriptor, ComponentInfo, StorageType},
         entity::hash_set::EntityHashSet,
@@ -4310,4 +4349,17 @@ mod tests {
         world.remove_resource::<DefaultQueryFilters>();
         assert_eq!(2, world.query::<&Foo>().iter(&world).count());
     }
+
+    #[test]
+    #[should_panic(expected = "requires")]
+    fn archetype_invariant_requires() {
+        let mut world = World::new();
+        let foo = world.register_component::<Foo>();
+        let bar = world.register_component::<Bar>();
+        world.register_archetype_invariant(ArchetypeInvariant::Requires(foo, bar));
+
+        let entity = world.spawn((Foo, Bar)).id();
+        world.spawn(Bar);
+        world.entity_mut(entity).remove::<Bar>();
+    }
 }
@@ -18,6 +18,30 @@
 //! ```
 //!
 //! See the documentation on [Gizmos](crate::gizmos::Gizmos) for more examples.
+//!
+//! Gizmos can be drawn from any system, are batched and rendered by both 2D and 3D cameras,
+//! and only last for the frame they were drawn in, so there is nothing to clean up.
+//! How they are drawn can be configured through the [`GizmoConfigStore`](crate::config::GizmoConfigStore).
+//! For example, gizmos can be drawn on top of all geometry by disabling their depth test:
+//! ```
+//! # use bevy_gizmos::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_math::prelude::*;
+//! # use bevy_color::palettes::basic::{BLUE, GREEN, RED};
+//! fn draw(mut gizmos: Gizmos) {
+//!     gizmos.sphere(Isometry3d::IDENTITY, 1.0, RED);
+//!     gizmos.arrow(Vec3::ZERO, Vec3::Y, BLUE);
+//!     gizmos.rect_2d(Isometry2d::IDENTITY, Vec2::ONE, GREEN);
+//! }
+//!
+//! fn draw_on_top(mut config_store: ResMut<GizmoConfigStore>) {
+//!     let (config, _) = config_store.config_mut::<DefaultGizmoConfigGroup>();
+//!     // A depth bias of -1 makes gizmos always render in front of other things.
+//!     config.depth_bias = -1.0;
+//! }
+//! # bevy_ecs::system::assert_is_system(draw);
+//! # bevy_ecs::system::assert_is_system(draw_on_top);
+//! ```
 
 // Required to make proc macros work in bevy itself.
 extern crate self as bevy_gizmos;
@@ -3,12 +3,20 @@
 #[cfg(feature = "bevy_animation")]
 use bevy_animation::AnimationClip;
 use bevy_asset::{Asset, Handle};
-use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_ecs::{
+    component::Component,
+    observer::Trigger,
+    reflect::ReflectComponent,
+    resource::Resource,
+    system::{Commands, EntityCommands, Query, Res},
+    world::OnAdd,
+};
 use bevy_pbr::StandardMaterial;
 use bevy_platform_support::collections::HashMap;
 use bevy_reflect::{prelude::ReflectDefault, Reflect, TypePath};
 use bevy_render::mesh::{skinning::SkinnedMeshInverseBindposes, Mesh};
 use bevy_scene::Scene;
+use tracing::warn;
 
 use crate::GltfAssetLabel;
 
@@ -99,6 +107,8 @@ pub struct GltfNode {
     pub index: usize,
     /// Computed name for a node - either a user defined node name from gLTF or a generated name from index
     pub name: String,
+    /// Parent of the node, or `None` if this is a root node of its scene.
+    pub parent: Option<Handle<GltfNode>>,
     /// Direct children of the node.
     pub children: Vec<Handle<GltfNode>>,
     /// Mesh of the node.
@@ -131,6 +141,7 @@ impl GltfNode {
             } else {
                 format!("GltfNode{}", node.index())
             },
+            parent: None,
             children,
             mesh,
             transform,
@@ -277,6 +288,77 @@ impl From<&serde_json::value::RawValue> for GltfExtras {
     }
 }
 
+/// A function that turns the parsed glTF extras of an entity into components.
+///
+/// Hooks are registered with [`GltfPlugin::add_extras_hook`] and run whenever [`GltfExtras`],
+/// [`GltfSceneExtras`], [`GltfMeshExtras`] or [`GltfMaterialExtras`] is added to an entity,
+/// which happens when a glTF scene is spawned. An entity can receive several of them, in which
+/// case the hooks run once for each.
+///
+/// [`GltfPlugin::add_extras_hook`]: crate::GltfPlugin::add_extras_hook
+pub type GltfExtrasHook = fn(&serde_json::Value, &mut EntityCommands);
+
+/// The [`GltfExtrasHook`]s run on every entity that receives glTF extras.
+#[derive(Resource, Clone, Default)]
+pub struct GltfExtrasHooks(pub Vec<GltfExtrasHook>);
+
+/// A component holding the extras of a glTF node, scene, mesh or material.
+pub(crate) trait ExtrasComponent: Component {
+    /// The raw JSON of the extras.
+    fn extras(&self) -> &str;
+}
+
+impl ExtrasComponent for GltfExtras {
+    fn extras(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ExtrasComponent for GltfSceneExtras {
+    fn extras(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ExtrasComponent for GltfMeshExtras {
+    fn extras(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ExtrasComponent for GltfMaterialExtras {
+    fn extras(&self) -> &str {
+        &self.value
+    }
+}
+
+/// Runs the registered [`GltfExtrasHooks`] for an entity that just received the extras `E`.
+pub(crate) fn run_gltf_extras_hooks<E: ExtrasComponent>(
+    trigger: Trigger<OnAdd, E>,
+    extras: Query<&E>,
+    hooks: Res<GltfExtrasHooks>,
+    mut commands: Commands,
+) {
+    if hooks.0.is_empty() {
+        return;
+    }
+    let Ok(extras) = extras.get(trigger.target()) else {
+        return;
+    };
+    let value = match serde_json::from_str::<serde_json::Value>(extras.extras()) {
+        Ok(value) => value,
+        Err(err) => {
+            warn!("Failed to parse glTF extras of {}: {err}", trigger.target());
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(trigger.target());
+    for hook in &hooks.0 {
+        hook(&value, &mut entity);
+    }
+}
+
 /// Additional untyped data that can be present on most glTF types at the scene level.
 ///
 /// See [the relevant glTF specification section](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#reference-extras).
@@ -313,3 +395,59 @@ pub struct GltfMaterialExtras {
 #[derive(Clone, Debug, Reflect, Default, Component)]
 #[reflect(Component)]
 pub struct GltfMaterialName(pub String);
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Health(u64);
+
+    fn health_hook(extras: &serde_json::Value, entity: &mut EntityCommands) {
+        if let Some(health) = extras.get("health").and_then(serde_json::Value::as_u64) {
+            entity.insert(Health(health));
+        }
+    }
+
+    #[test]
+    fn extras_hooks() {
+        let mut app = App::new();
+        app.insert_resource(GltfExtrasHooks(vec![health_hook]))
+            .add_observer(run_gltf_extras_hooks::<GltfExtras>)
+            .add_observer(run_gltf_extras_hooks::<GltfSceneExtras>)
+            .add_observer(run_gltf_extras_hooks::<GltfMeshExtras>)
+            .add_observer(run_gltf_extras_hooks::<GltfMaterialExtras>);
+
+        let value = String::from(r#"{"health": 10}"#);
+        let world = app.world_mut();
+        let entities = [
+            world
+                .spawn(GltfExtras {
+                    value: value.clone(),
+                })
+                .id(),
+            world
+                .spawn(GltfSceneExtras {
+                    value: value.clone(),
+                })
+                .id(),
+            world
+                .spawn(GltfMeshExtras {
+                    value: value.clone(),
+                })
+                .id(),
+            world.spawn(GltfMaterialExtras { value }).id(),
+            world
+                .spawn(GltfExtras {
+                    value: "not json".into(),
+                })
+                .id(),
+        ];
+        world.flush();
+
+        let health = entities.map(|entity| world.get::<Health>(entity).map(|health| health.0));
+        assert_eq!(health, [Some(10), Some(10), Some(10), Some(10), None]);
+    }
+}
@@ -0,0 +1,788 @@
+//! Exporting parts of a [`World`] to glTF.
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+#[cfg(feature = "bevy_animation")]
+use bevy_animation::{
+    graph::{AnimationGraph, AnimationGraphHandle, AnimationNodeType},
+    prelude::{Animatable, EvaluatorId},
+    AnimationClip, AnimationTarget, AnimationTargetId, VariableCurve,
+};
+use bevy_asset::{AssetId, Assets};
+use bevy_color::ColorToComponents;
+use bevy_ecs::{
+    entity::{hash_set::EntityHashSet, Entity},
+    hierarchy::{ChildOf, Children},
+    name::Name,
+    world::World,
+};
+#[cfg(feature = "bevy_animation")]
+use bevy_math::{curve::Interval, Quat, Vec3};
+use bevy_pbr::{MeshMaterial3d, StandardMaterial};
+use bevy_platform_support::collections::HashMap;
+#[cfg(feature = "bevy_animation")]
+use bevy_platform_support::collections::HashSet;
+#[cfg(feature = "bevy_animation")]
+use bevy_reflect::{TypeInfo, Typed};
+use bevy_render::{
+    alpha::AlphaMode,
+    mesh::{Indices, Mesh, Mesh3d, VertexAttributeValues},
+    render_resource::PrimitiveTopology,
+};
+use bevy_transform::components::Transform;
+use serde_json::{json, Value};
+use thiserror::Error;
+use tracing::warn;
+
+/// An error that occurs when exporting entities to glTF.
+#[derive(Error, Debug)]
+pub enum GltfExportError {
+    /// The entity to export doesn't exist.
+    #[error("entity {0} does not exist")]
+    EntityNotFound(Entity),
+    /// The glTF document could not be serialized.
+    #[error("failed to serialize the glTF document: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+/// A glTF document produced by [`export_gltf`].
+///
+/// The document consists of the glTF JSON and a single binary buffer holding all vertex data,
+/// and can be written either as a self-contained `.gltf` file or as a `.glb` file.
+#[derive(Debug, Clone)]
+pub struct GltfExport {
+    /// The glTF JSON, without the `uri` of the binary buffer.
+    pub json: Value,
+    /// The binary buffer referenced by the accessors of the document.
+    pub buffer: Vec<u8>,
+}
+
+impl GltfExport {
+    /// Serializes the document as a `.gltf` file, embedding the binary buffer as a data URI.
+    pub fn to_gltf(&self) -> Result<Vec<u8>, GltfExportError> {
+        let mut json = self.json.clone();
+        if let Some(buffer) = json["buffers"].get_mut(0) {
+            buffer["uri"] = Value::String(format!(
+                "data:application/octet-stream;base64,{}",
+                BASE64_STANDARD.encode(&self.buffer)
+            ));
+        }
+        Ok(serde_json::to_vec_pretty(&json)?)
+    }
+
+    /// Serializes the document as a binary `.glb` file.
+    pub fn to_glb(&self) -> Result<Vec<u8>, GltfExportError> {
+        const MAGIC: &[u8; 4] = b"glTF";
+        const CHUNK_JSON: &[u8; 4] = b"JSON";
+        const CHUNK_BIN: &[u8; 4] = b"BIN\0";
+
+        let mut json = serde_json::to_vec(&self.json)?;
+        pad_to_four(&mut json, b' ');
+        let mut buffer = self.buffer.clone();
+        pad_to_four(&mut buffer, 0);
+
+        let mut total_length = 12 + 8 + json.len();
+        if !buffer.is_empty() {
+            total_length += 8 + buffer.len();
+        }
+
+        let mut glb = Vec::with_capacity(total_length);
+        glb.extend_from_slice(MAGIC);
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&(total_length as u32).to_le_bytes());
+        glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        glb.extend_from_slice(CHUNK_JSON);
+        glb.extend_from_slice(&json);
+        if !buffer.is_empty() {
+            glb.extend_from_slice(&(buffer.len() as u32).to_le_bytes());
+            glb.extend_from_slice(CHUNK_BIN);
+            glb.extend_from_slice(&buffer);
+        }
+        Ok(glb)
+    }
+}
+
+/// Exports the given root entities and their descendants as a single glTF scene.
+///
+/// The names, [`Transform`]s and hierarchy of all entities are exported, along with the
+/// [`Mesh3d`]es and [`StandardMaterial`]s of the entities that have them. Only the position,
+/// normal and first UV channel of triangle list meshes are written, and material textures are
+/// not exported yet. Skins, cameras and lights are not exported either.
+///
+/// With the `bevy_animation` feature, the [`AnimationClip`]s in the [`AnimationGraph`]s of the
+/// [`AnimationPlayer`]s that animate exported [`AnimationTarget`]s are exported as well. Only the
+/// curves that animate the translation, rotation or scale of exported nodes are written, sampled
+/// at 30 frames per second with linear interpolation.
+///
+/// [`AnimationClip`]: bevy_animation::AnimationClip
+/// [`AnimationGraph`]: bevy_animation::graph::AnimationGraph
+/// [`AnimationPlayer`]: bevy_animation::AnimationPlayer
+/// [`AnimationTarget`]: bevy_animation::AnimationTarget
+///
+/// Roots that are listed twice, or that are descendants of another root, are only exported once
+/// as part of the topmost root.
+pub fn export_gltf(
+    world: &World,
+    roots: impl IntoIterator<Item = Entity>,
+) -> Result<GltfExport, GltfExportError> {
+    let mut exporter = Exporter {
+        world,
+        mesh_assets: world.get_resource::<Assets<Mesh>>(),
+        material_assets: world.get_resource::<Assets<StandardMaterial>>(),
+        nodes: Vec::new(),
+        meshes: Vec::new(),
+        mesh_indices: HashMap::default(),
+        materials: Vec::new(),
+        material_indices: HashMap::default(),
+        accessors: Vec::new(),
+        buffer_views: Vec::new(),
+        buffer: Vec::new(),
+        extensions_used: Vec::new(),
+        animations: Vec::new(),
+        #[cfg(feature = "bevy_animation")]
+        animation_players: Vec::new(),
+        #[cfg(feature = "bevy_animation")]
+        animation_targets: HashMap::default(),
+    };
+
+    let roots: Vec<Entity> = roots.into_iter().collect();
+    let root_set: EntityHashSet = roots.iter().copied().collect();
+    let mut exported_roots = EntityHashSet::default();
+    let mut scene_nodes = Vec::new();
+    for root in roots {
+        if exported_roots.insert(root) && !has_ancestor_in(world, root, &root_set) {
+            scene_nodes.push(exporter.export_node(root)?);
+        }
+    }
+    #[cfg(feature = "bevy_animation")]
+    exporter.export_animations();
+
+    let mut json = json!({
+        "asset": { "version": "2.0", "generator": "Bevy" },
+        "scene": 0,
+        "scenes": [{ "nodes": scene_nodes }],
+        "nodes": exporter.nodes,
+    });
+    for (key, values) in [
+        ("meshes", exporter.meshes),
+        ("materials", exporter.materials),
+        ("animations", exporter.animations),
+        ("accessors", exporter.accessors),
+        ("bufferViews", exporter.buffer_views),
+    ] {
+        if !values.is_empty() {
+            json[key] = Value::Array(values);
+        }
+    }
+    if !exporter.extensions_used.is_empty() {
+        json["extensionsUsed"] = json!(exporter.extensions_used);
+    }
+    if !exporter.buffer.is_empty() {
+        json["buffers"] = json!([{ "byteLength": exporter.buffer.len() }]);
+    }
+
+    Ok(GltfExport {
+        json,
+        buffer: exporter.buffer,
+    })
+}
+
+/// Returns `true` if one of the ancestors of `entity` is in `entities`.
+fn has_ancestor_in(world: &World, entity: Entity, entities: &EntityHashSet) -> bool {
+    let mut current = entity;
+    while let Some(child_of) = world.get::<ChildOf>(current) {
+        if entities.contains(&child_of.parent) {
+            return true;
+        }
+        current = child_of.parent;
+    }
+    false
+}
+
+struct Exporter<'w> {
+    world: &'w World,
+    mesh_assets: Option<&'w Assets<Mesh>>,
+    material_assets: Option<&'w Assets<StandardMaterial>>,
+    nodes: Vec<Value>,
+    meshes: Vec<Value>,
+    mesh_indices: HashMap<(AssetId<Mesh>, Option<AssetId<StandardMaterial>>), Option<usize>>,
+    materials: Vec<Value>,
+    material_indices: HashMap<AssetId<StandardMaterial>, usize>,
+    accessors: Vec<Value>,
+    buffer_views: Vec<Value>,
+    buffer: Vec<u8>,
+    extensions_used: Vec<&'static str>,
+    animations: Vec<Value>,
+    /// The players of the exported animation targets, in the order they were found.
+    #[cfg(feature = "bevy_animation")]
+    animation_players: Vec<Entity>,
+    /// The node indices of the exported animation targets.
+    #[cfg(feature = "bevy_animation")]
+    animation_targets: HashMap<(Entity, AnimationTargetId), usize>,
+}
+
+impl Exporter<'_> {
+    fn export_node(&mut self, entity: Entity) -> Result<usize, GltfExportError> {
+        let entity_ref = self
+            .world
+            .get_entity(entity)
+            .map_err(|_| GltfExportError::EntityNotFound(entity))?;
+
+        let index = self.nodes.len();
+        self.nodes.push(Value::Null);
+
+        let mut node = json!({});
+        if let Some(name) = entity_ref.get::<Name>() {
+            node["name"] = Value::String(name.as_str().to_owned());
+        }
+        if let Some(transform) = entity_ref.get::<Transform>() {
+            if *transform != Transform::IDENTITY {
+                node["translation"] = json!(transform.translation.to_array());
+                node["rotation"] = json!(transform.rotation.to_array());
+                node["scale"] = json!(transform.scale.to_array());
+            }
+        }
+        if let Some(mesh) = entity_ref.get::<Mesh3d>() {
+            let material = entity_ref
+                .get::<MeshMaterial3d<StandardMaterial>>()
+                .map(|material| material.id());
+            if let Some(mesh) = self.export_mesh(mesh.id(), material) {
+                node["mesh"] = json!(mesh);
+            }
+        }
+        #[cfg(feature = "bevy_animation")]
+        if let Some(target) = entity_ref.get::<AnimationTarget>() {
+            if !self.animation_players.contains(&target.player) {
+                self.animation_players.push(target.player);
+            }
+            self.animation_targets
+                .insert((target.player, target.id), index);
+        }
+        if let Some(children) = entity_ref.get::<Children>() {
+            let mut child_indices = Vec::with_capacity(children.len());
+            for &child in children {
+                child_indices.push(self.export_node(child)?);
+            }
+            node["children"] = json!(child_indices);
+        }
+
+        self.nodes[index] = node;
+        Ok(index)
+    }
+
+    fn export_mesh(
+        &mut self,
+        mesh_id: AssetId<Mesh>,
+        material_id: Option<AssetId<StandardMaterial>>,
+    ) -> Option<usize> {
+        if let Some(index) = self.mesh_indices.get(&(mesh_id, material_id)) {
+            return *index;
+        }
+
+        let index = self.write_mesh(mesh_id, material_id);
+        self.mesh_indices.insert((mesh_id, material_id), index);
+        index
+    }
+
+    fn write_mesh(
+        &mut self,
+        mesh_id: AssetId<Mesh>,
+        material_id: Option<AssetId<StandardMaterial>>,
+    ) -> Option<usize> {
+        let mesh = self.mesh_assets?.get(mesh_id)?;
+        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+            warn!("Skipping mesh {mesh_id} during glTF export: only triangle lists are supported");
+            return None;
+        }
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            warn!("Skipping mesh {mesh_id} during glTF export: it has no positions");
+            return None;
+        };
+
+        let mut attributes = json!({
+            "POSITION": self.write_vec3_accessor(positions, true),
+        });
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
+        {
+            attributes["NORMAL"] = json!(self.write_vec3_accessor(normals, false));
+        }
+        if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            let bytes: Vec<u8> = uvs.iter().flatten().flat_map(|v| v.to_le_bytes()).collect();
+            let view = self.write_buffer_view(&bytes, Some(ARRAY_BUFFER));
+            attributes["TEXCOORD_0"] = json!(self.push_accessor(json!({
+                "bufferView": view,
+                "componentType": FLOAT,
+                "count": uvs.len(),
+                "type": "VEC2",
+            })));
+        }
+
+        let mut primitive = json!({ "attributes": attributes, "mode": 4 });
+        if let Some(indices) = mesh.indices() {
+            let (bytes, component_type): (Vec<u8>, _) = match indices {
+                Indices::U16(indices) => (
+                    indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+                    UNSIGNED_SHORT,
+                ),
+                Indices::U32(indices) => (
+                    indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+                    UNSIGNED_INT,
+                ),
+            };
+            let view = self.write_buffer_view(&bytes, Some(ELEMENT_ARRAY_BUFFER));
+            primitive["indices"] = json!(self.push_accessor(json!({
+                "bufferView": view,
+                "componentType": component_type,
+                "count": indices.len(),
+                "type": "SCALAR",
+            })));
+        }
+        if let Some(material) = material_id.and_then(|id| self.export_material(id)) {
+            primitive["material"] = json!(material);
+        }
+
+        self.meshes.push(json!({ "primitives": [primitive] }));
+        Some(self.meshes.len() - 1)
+    }
+
+    fn export_material(&mut self, material_id: AssetId<StandardMaterial>) -> Option<usize> {
+        if let Some(index) = self.material_indices.get(&material_id) {
+            return Some(*index);
+        }
+        let material = self.material_assets?.get(material_id)?;
+
+        let mut json = json!({
+            "pbrMetallicRoughness": {
+                "baseColorFactor": material.base_color.to_linear().to_f32_array(),
+                "metallicFactor": material.metallic,
+                "roughnessFactor": material.perceptual_roughness,
+            },
+            "doubleSided": material.double_sided,
+        });
+
+        // glTF clamps `emissiveFactor` to [0, 1], so brighter emissive colors are normalized and
+        // their intensity is written with `KHR_materials_emissive_strength`.
+        let emissive = material.emissive.to_f32_array_no_alpha();
+        let strength = emissive.into_iter().fold(0.0, f32::max);
+        if strength > 1.0 {
+            json["emissiveFactor"] = json!(emissive.map(|c| c / strength));
+            json["extensions"] = json!({
+                EMISSIVE_STRENGTH: { "emissiveStrength": strength },
+            });
+            self.use_extension(EMISSIVE_STRENGTH);
+        } else {
+            json["emissiveFactor"] = json!(emissive);
+        }
+        match material.alpha_mode {
+            AlphaMode::Opaque => {}
+            AlphaMode::Mask(cutoff) => {
+                json["alphaMode"] = json!("MASK");
+                json["alphaCutoff"] = json!(cutoff);
+            }
+            _ => json["alphaMode"] = json!("BLEND"),
+        }
+
+        let index = self.materials.len();
+        self.materials.push(json);
+        self.material_indices.insert(material_id, index);
+        Some(index)
+    }
+
+    fn use_extension(&mut self, extension: &'static str) {
+        if !self.extensions_used.contains(&extension) {
+            self.extensions_used.push(extension);
+        }
+    }
+
+    fn write_vec3_accessor(&mut self, values: &[[f32; 3]], with_bounds: bool) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flatten()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let view = self.write_buffer_view(&bytes, Some(ARRAY_BUFFER));
+        let mut accessor = json!({
+            "bufferView": view,
+            "componentType": FLOAT,
+            "count": values.len(),
+            "type": "VEC3",
+        });
+        // The glTF specification requires bounds on `POSITION` accessors.
+        if with_bounds && !values.is_empty() {
+            let mut min = [f32::MAX; 3];
+            let mut max = [f32::MIN; 3];
+            for value in values {
+                for ((min, max), value) in min.iter_mut().zip(&mut max).zip(value) {
+                    *min = min.min(*value);
+                    *max = max.max(*value);
+                }
+            }
+            accessor["min"] = json!(min);
+            accessor["max"] = json!(max);
+        }
+        self.push_accessor(accessor)
+    }
+
+    fn write_float_accessor(&mut self, values: &[f32], count: usize, kind: &str) -> usize {
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let view = self.write_buffer_view(&bytes, None);
+        self.push_accessor(json!({
+            "bufferView": view,
+            "componentType": FLOAT,
+            "count": count,
+            "type": kind,
+        }))
+    }
+
+    fn push_accessor(&mut self, accessor: Value) -> usize {
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    fn write_buffer_view(&mut self, bytes: &[u8], target: Option<u32>) -> usize {
+        pad_to_four(&mut self.buffer, 0);
+        let mut view = json!({
+            "buffer": 0,
+            "byteOffset": self.buffer.len(),
+            "byteLength": bytes.len(),
+        });
+        if let Some(target) = target {
+            view["target"] = json!(target);
+        }
+        self.buffer.extend_from_slice(bytes);
+        self.buffer_views.push(view);
+        self.buffer_views.len() - 1
+    }
+}
+
+#[cfg(feature = "bevy_animation")]
+impl Exporter<'_> {
+    fn export_animations(&mut self) {
+        let (Some(graphs), Some(clips)) = (
+            self.world.get_resource::<Assets<AnimationGraph>>(),
+            self.world.get_resource::<Assets<AnimationClip>>(),
+        ) else {
+            return;
+        };
+
+        for player in core::mem::take(&mut self.animation_players) {
+            let Some(graph) = self
+                .world
+                .get::<AnimationGraphHandle>(player)
+                .and_then(|handle| graphs.get(handle))
+            else {
+                continue;
+            };
+            let mut exported_clips = HashSet::default();
+            for node in graph.graph.node_weights() {
+                let AnimationNodeType::Clip(clip) = &node.node_type else {
+                    continue;
+                };
+                if exported_clips.insert(clip.id()) {
+                    if let Some(clip) = clips.get(clip) {
+                        self.export_animation(player, clip);
+                    }
+                }
+            }
+        }
+    }
+
+    fn export_animation(&mut self, player: Entity, clip: &AnimationClip) {
+        let mut samplers = Vec::new();
+        let mut channels = Vec::new();
+        for (target_id, curves) in clip.curves() {
+            let Some(&node) = self.animation_targets.get(&(player, *target_id)) else {
+                continue;
+            };
+            for curve in curves {
+                let Some(path) = transform_path(curve) else {
+                    warn!(
+                        "Skipping an animation curve of target {target_id:?} during glTF export: \
+                        only transform curves are supported"
+                    );
+                    continue;
+                };
+
+                let times = sample_times(curve.0.domain(), clip.duration());
+                let output = match path {
+                    "rotation" => sample_curve(curve, &times, Quat::to_array).map(|v| (v, "VEC4")),
+                    _ => sample_curve(curve, &times, Vec3::to_array).map(|v| (v, "VEC3")),
+                };
+                let Some((values, kind)) = output else {
+                    continue;
+                };
+
+                let input = self.write_float_accessor(&times, times.len(), "SCALAR");
+                // The glTF specification requires bounds on animation inputs.
+                self.accessors[input]["min"] = json!([times[0]]);
+                self.accessors[input]["max"] = json!([times[times.len() - 1]]);
+                let output = self.write_float_accessor(&values, times.len(), kind);
+
+                samplers.push(json!({
+                    "input": input,
+                    "output": output,
+                    "interpolation": "LINEAR",
+                }));
+                channels.push(json!({
+                    "sampler": samplers.len() - 1,
+                    "target": { "node": node, "path": path },
+                }));
+            }
+        }
+
+        if !channels.is_empty() {
+            self.animations
+                .push(json!({ "samplers": samplers, "channels": channels }));
+        }
+    }
+}
+
+/// Returns the glTF path of the [`Transform`] field animated by `curve`, if any.
+#[cfg(feature = "bevy_animation")]
+fn transform_path(curve: &VariableCurve) -> Option<&'static str> {
+    let EvaluatorId::ComponentField(id) = curve.0.evaluator_id() else {
+        return None;
+    };
+    let (type_id, index) = **id;
+    let TypeInfo::Struct(info) = Transform::type_info() else {
+        return None;
+    };
+    if type_id != core::any::TypeId::of::<Transform>() {
+        return None;
+    }
+    match info.field_at(index)?.name() {
+        path @ ("translation" | "rotation" | "scale") => Some(path),
+        _ => None,
+    }
+}
+
+/// Returns the times at which a curve with the given domain is sampled in a clip of the given
+/// duration.
+#[cfg(feature = "bevy_animation")]
+fn sample_times(domain: Interval, duration: f32) -> Vec<f32> {
+    const SAMPLES_PER_SECOND: f32 = 30.0;
+
+    let start = domain.start().max(0.0);
+    let end = domain.end().min(duration).max(start);
+    let steps = ((end - start) * SAMPLES_PER_SECOND).ceil() as usize;
+    if steps == 0 {
+        return vec![start];
+    }
+    (0..=steps)
+        .map(|step| start + (end - start) * step as f32 / steps as f32)
+        .collect()
+}
+
+/// Samples `curve` at the given times, flattening the sampled values into floats.
+#[cfg(feature = "bevy_animation")]
+fn sample_curve<A: Animatable, const N: usize>(
+    curve: &VariableCurve,
+    times: &[f32],
+    to_array: fn(&A) -> [f32; N],
+) -> Option<Vec<f32>> {
+    let mut values = Vec::with_capacity(times.len() * N);
+    for &t in times {
+        values.extend(to_array(&curve.sample_animatable::<A>(t)?));
+    }
+    Some(values)
+}
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_SHORT: u32 = 5123;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+const EMISSIVE_STRENGTH: &str = "KHR_materials_emissive_strength";
+
+fn pad_to_four(bytes: &mut Vec<u8>, padding: u8) {
+    while bytes.len() % 4 != 0 {
+        bytes.push(padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_hierarchy() {
+        let mut world = World::new();
+        let child = world
+            .spawn((Name::new("Child"), Transform::from_xyz(1.0, 2.0, 3.0)))
+            .id();
+        let root = world.spawn(Name::new("Root")).add_child(child).id();
+
+        let export = export_gltf(&world, [root]).unwrap();
+        assert_eq!(export.json["scenes"][0]["nodes"], json!([0]));
+        assert_eq!(export.json["nodes"][0]["name"], "Root");
+        assert_eq!(export.json["nodes"][0]["children"], json!([1]));
+        assert_eq!(
+            export.json["nodes"][1]["translation"],
+            json!([1.0, 2.0, 3.0])
+        );
+        assert!(export.buffer.is_empty());
+
+        let glb = export.to_glb().unwrap();
+        assert_eq!(&glb[0..4], b"glTF");
+        assert_eq!(glb.len() % 4, 0);
+    }
+
+    #[test]
+    fn export_nested_roots_once() {
+        let mut world = World::new();
+        let grandchild = world.spawn(Name::new("Grandchild")).id();
+        let child = world.spawn(Name::new("Child")).add_child(grandchild).id();
+        let root = world.spawn(Name::new("Root")).add_child(child).id();
+        let other = world.spawn(Name::new("Other")).id();
+
+        let export = export_gltf(&world, [grandchild, root, other, root]).unwrap();
+        assert_eq!(export.json["scenes"][0]["nodes"], json!([0, 3]));
+        assert_eq!(export.json["nodes"].as_array().unwrap().len(), 4);
+        assert_eq!(export.json["nodes"][2]["name"], "Grandchild");
+        assert_eq!(export.json["nodes"][3]["name"], "Other");
+    }
+
+    #[test]
+    fn export_mesh_and_material() {
+        use bevy_color::Color;
+        use bevy_render::render_asset::RenderAssetUsages;
+
+        let mut world = World::new();
+        let mut meshes = Assets::<Mesh>::default();
+        let mesh = meshes.add(
+            Mesh::new(
+                PrimitiveTopology::TriangleList,
+                RenderAssetUsages::default(),
+            )
+            .with_inserted_attribute(
+                Mesh::ATTRIBUTE_POSITION,
+                vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 2.0, 0.0]],
+            )
+            .with_inserted_indices(Indices::U16(vec![0, 1, 2])),
+        );
+        let mut materials = Assets::<StandardMaterial>::default();
+        let material = materials.add(StandardMaterial {
+            base_color: Color::WHITE,
+            alpha_mode: AlphaMode::Mask(0.5),
+            ..Default::default()
+        });
+        world.insert_resource(meshes);
+        world.insert_resource(materials);
+
+        // Both entities share the same mesh and material.
+        let first = world
+            .spawn((Mesh3d(mesh.clone()), MeshMaterial3d(material.clone())))
+            .id();
+        let second = world.spawn((Mesh3d(mesh), MeshMaterial3d(material))).id();
+
+        let export = export_gltf(&world, [first, second]).unwrap();
+        let json = &export.json;
+        assert_eq!(json["nodes"][0]["mesh"], json!(0));
+        assert_eq!(json["nodes"][1]["mesh"], json!(0));
+        assert_eq!(json["meshes"].as_array().unwrap().len(), 1);
+        assert_eq!(json["materials"][0]["alphaMode"], "MASK");
+
+        let primitive = &json["meshes"][0]["primitives"][0];
+        let position =
+            &json["accessors"][primitive["attributes"]["POSITION"].as_u64().unwrap() as usize];
+        assert_eq!(position["max"], json!([1.0, 2.0, 0.0]));
+        assert_eq!(json["buffers"][0]["byteLength"], json!(export.buffer.len()));
+
+        assert_eq!(
+            json["materials"][0]["emissiveFactor"],
+            json!([0.0, 0.0, 0.0])
+        );
+        assert!(json.get("extensionsUsed").is_none());
+
+        let gltf = export.to_gltf().unwrap();
+        assert!(gltf::Gltf::from_slice(&gltf).is_ok());
+    }
+
+    #[test]
+    fn export_emissive_strength() {
+        use bevy_color::LinearRgba;
+
+        let mut world = World::new();
+        let mut meshes = Assets::<Mesh>::default();
+        let mesh = meshes.add(Mesh::from(bevy_math::primitives::Cuboid::default()));
+        let mut materials = Assets::<StandardMaterial>::default();
+        let material = materials.add(StandardMaterial {
+            emissive: LinearRgba::rgb(4.0, 2.0, 0.0),
+            ..Default::default()
+        });
+        world.insert_resource(meshes);
+        world.insert_resource(materials);
+        let entity = world.spawn((Mesh3d(mesh), MeshMaterial3d(material))).id();
+
+        let export = export_gltf(&world, [entity]).unwrap();
+        let material = &export.json["materials"][0];
+        assert_eq!(material["emissiveFactor"], json!([1.0, 0.5, 0.0]));
+        assert_eq!(
+            material["extensions"][EMISSIVE_STRENGTH]["emissiveStrength"],
+            json!(4.0)
+        );
+        assert_eq!(export.json["extensionsUsed"], json!([EMISSIVE_STRENGTH]));
+    }
+
+    #[cfg(feature = "bevy_animation")]
+    #[test]
+    fn export_animation() {
+        use bevy_animation::{
+            animated_field,
+            prelude::{AnimatableCurve, AnimatableKeyframeCurve, AnimatedField},
+            AnimationPlayer,
+        };
+
+        let mut world = World::new();
+        let target_id = AnimationTargetId::from_name(&Name::new("Cube"));
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_target(
+            target_id,
+            AnimatableCurve::new(
+                animated_field!(Transform::translation),
+                AnimatableKeyframeCurve::new([(0.0, Vec3::ZERO), (1.0, Vec3::X)]).unwrap(),
+            ),
+        );
+        let mut clips = Assets::<AnimationClip>::default();
+        let (graph, _) = AnimationGraph::from_clip(clips.add(clip));
+        let mut graphs = Assets::<AnimationGraph>::default();
+        let graph = graphs.add(graph);
+        world.insert_resource(clips);
+        world.insert_resource(graphs);
+
+        let root = world
+            .spawn((AnimationPlayer::default(), AnimationGraphHandle(graph)))
+            .id();
+        let cube = world
+            .spawn((
+                Name::new("Cube"),
+                AnimationTarget {
+                    id: target_id,
+                    player: root,
+                },
+            ))
+            .id();
+        world.entity_mut(root).add_child(cube);
+
+        let export = export_gltf(&world, [root]).unwrap();
+        let json = &export.json;
+        let animation = &json["animations"][0];
+        assert_eq!(
+            animation["channels"][0]["target"],
+            json!({ "node": 1, "path": "translation" })
+        );
+        let sampler =
+            &animation["samplers"][animation["channels"][0]["sampler"].as_u64().unwrap() as usize];
+        let input = &json["accessors"][sampler["input"].as_u64().unwrap() as usize];
+        assert_eq!(input["count"], json!(31));
+        assert_eq!(input["max"], json!([1.0]));
+        let output = &json["accessors"][sampler["output"].as_u64().unwrap() as usize];
+        assert_eq!(output["type"], "VEC3");
+
+        let gltf = export.to_gltf().unwrap();
+        assert!(gltf::Gltf::from_slice(&gltf).is_ok());
+    }
+}
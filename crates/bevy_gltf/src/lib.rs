@@ -91,6 +91,7 @@
 //! You can use [`GltfAssetLabel`] to ensure you are using the correct label.
 
 mod assets;
+mod export;
 mod label;
 mod loader;
 mod vertex_attributes;
@@ -112,12 +113,13 @@ pub mod prelude {
     pub use crate::{assets::Gltf, assets::GltfExtras, label::GltfAssetLabel};
 }
 
-pub use {assets::*, label::GltfAssetLabel, loader::*};
+pub use {assets::*, export::*, label::GltfAssetLabel, loader::*};
 
 /// Adds support for glTF file loading to the app.
 #[derive(Default)]
 pub struct GltfPlugin {
     custom_vertex_attributes: HashMap<Box<str>, MeshVertexAttribute>,
+    extras_hooks: Vec<GltfExtrasHook>,
 }
 
 impl GltfPlugin {
@@ -134,6 +136,31 @@ impl GltfPlugin {
         self.custom_vertex_attributes.insert(name.into(), attribute);
         self
     }
+
+    /// Register a hook that converts the extras of spawned glTF nodes, scenes, meshes and
+    /// materials into components.
+    ///
+    /// The hook receives the extras parsed as JSON and the commands of the entity they were
+    /// found on, which lets level metadata authored in a DCC tool turn into gameplay components:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_gltf::GltfPlugin;
+    /// #[derive(Component)]
+    /// struct Health(f32);
+    ///
+    /// let plugin = GltfPlugin::default().add_extras_hook(|extras, entity| {
+    ///     if let Some(health) = extras.get("health").and_then(|health| health.as_f64()) {
+    ///         entity.insert(Health(health as f32));
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Additional hooks can be pushed to the [`GltfExtrasHooks`] resource after the plugin was added.
+    pub fn add_extras_hook(mut self, hook: GltfExtrasHook) -> Self {
+        self.extras_hooks.push(hook);
+        self
+    }
 }
 
 impl Plugin for GltfPlugin {
@@ -148,6 +175,11 @@ impl Plugin for GltfPlugin {
             .init_asset::<GltfPrimitive>()
             .init_asset::<GltfMesh>()
             .init_asset::<GltfSkin>()
+            .insert_resource(GltfExtrasHooks(self.extras_hooks.clone()))
+            .add_observer(assets::run_gltf_extras_hooks::<GltfExtras>)
+            .add_observer(assets::run_gltf_extras_hooks::<GltfSceneExtras>)
+            .add_observer(assets::run_gltf_extras_hooks::<GltfMeshExtras>)
+            .add_observer(assets::run_gltf_extras_hooks::<GltfMaterialExtras>)
             .preregister_asset_loader::<GltfLoader>(&["gltf", "glb"]);
     }
 
@@ -4,6 +4,8 @@ use bevy_math::Affine2;
 
 use gltf::{
     image::Source,
+    json::Value,
+    material::{NormalTexture, OcclusionTexture},
     texture::{MagFilter, MinFilter, Texture, TextureTransform, WrappingMode},
 };
 
@@ -103,6 +105,61 @@ pub(crate) fn texture_transform_to_affine2(texture_transform: TextureTransform)
     )
 }
 
+/// Returns the index of the `TEXCOORD` attribute used by a texture.
+///
+/// The `texCoord` field of the `KHR_texture_transform` extension, if present,
+/// takes precedence over the one of the texture info itself.
+pub(crate) fn texture_tex_coord(info: &impl TextureInfo) -> u32 {
+    info.transform_tex_coord()
+        .unwrap_or_else(|| info.info_tex_coord())
+}
+
+/// A reference from a glTF material to one of its textures.
+pub(crate) trait TextureInfo {
+    /// The `texCoord` field of the texture info itself.
+    fn info_tex_coord(&self) -> u32;
+
+    /// The `texCoord` field of the `KHR_texture_transform` extension of the texture info, if any.
+    fn transform_tex_coord(&self) -> Option<u32>;
+}
+
+impl TextureInfo for gltf::texture::Info<'_> {
+    fn info_tex_coord(&self) -> u32 {
+        self.tex_coord()
+    }
+
+    fn transform_tex_coord(&self) -> Option<u32> {
+        self.texture_transform()
+            .and_then(|texture_transform| texture_transform.tex_coord())
+    }
+}
+
+// The `gltf` crate only parses `KHR_texture_transform` for plain texture infos, so the
+// extension of normal and occlusion textures has to be read from the raw JSON.
+impl TextureInfo for NormalTexture<'_> {
+    fn info_tex_coord(&self) -> u32 {
+        self.tex_coord()
+    }
+
+    fn transform_tex_coord(&self) -> Option<u32> {
+        extension_tex_coord(self.extension_value("KHR_texture_transform")?)
+    }
+}
+
+impl TextureInfo for OcclusionTexture<'_> {
+    fn info_tex_coord(&self) -> u32 {
+        self.tex_coord()
+    }
+
+    fn transform_tex_coord(&self) -> Option<u32> {
+        extension_tex_coord(self.extension_value("KHR_texture_transform")?)
+    }
+}
+
+fn extension_tex_coord(texture_transform: &Value) -> Option<u32> {
+    texture_transform.get("texCoord")?.as_u64()?.try_into().ok()
+}
+
 #[cfg(any(
     feature = "pbr_anisotropy_texture",
     feature = "pbr_multi_layer_material_textures",
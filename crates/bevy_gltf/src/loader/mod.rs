@@ -2,6 +2,7 @@ mod extensions;
 mod gltf_ext;
 
 use std::{
+    borrow::Cow,
     io::Error,
     path::{Path, PathBuf},
 };
@@ -76,7 +77,9 @@ use self::{
         },
         mesh::{primitive_name, primitive_topology},
         scene::{collect_path, node_name, node_transform},
-        texture::{texture_handle, texture_sampler, texture_transform_to_affine2},
+        texture::{
+            texture_handle, texture_sampler, texture_tex_coord, texture_transform_to_affine2,
+        },
     },
 };
 
@@ -178,6 +181,31 @@ pub struct GltfLoaderSettings {
     pub load_lights: bool,
     /// If true, the loader will include the root of the gltf root node.
     pub include_source: bool,
+    /// If true, the loader will compute missing vertex tangents for every mesh primitive that
+    /// has normals.
+    ///
+    /// Otherwise, tangents are only computed for primitives whose material uses a normal map
+    /// or another texture that requires them.
+    #[serde(default)]
+    pub generate_tangents: bool,
+    /// If true, the loader will flip the V coordinate of all texture coordinates, which is
+    /// useful for assets exported by tools that use a bottom-left UV origin.
+    #[serde(default)]
+    pub flip_uvs: bool,
+    /// The uniform scale applied to the root entity of each loaded scene, to convert assets
+    /// authored in other units to meters.
+    ///
+    /// The [`GltfNode`](crate::GltfNode) and mesh assets are not affected.
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    /// Custom vertex attributes recognized for this file only, in addition to the ones
+    /// registered with [`GltfPlugin::add_custom_vertex_attribute`](crate::GltfPlugin::add_custom_vertex_attribute).
+    ///
+    /// These take precedence over the attributes of the plugin with the same name. As vertex
+    /// attributes can't be serialized, they can only be set through
+    /// [`AssetServer::load_with_settings`](bevy_asset::AssetServer::load_with_settings).
+    #[serde(skip)]
+    pub custom_vertex_attributes: HashMap<Box<str>, MeshVertexAttribute>,
 }
 
 impl Default for GltfLoaderSettings {
@@ -188,10 +216,18 @@ impl Default for GltfLoaderSettings {
             load_cameras: true,
             load_lights: true,
             include_source: false,
+            generate_tangents: false,
+            flip_uvs: false,
+            scale: default_scale(),
+            custom_vertex_attributes: HashMap::default(),
         }
     }
 }
 
+fn default_scale() -> f32 {
+    1.0
+}
+
 impl AssetLoader for GltfLoader {
     type Asset = Gltf;
     type Settings = GltfLoaderSettings;
@@ -235,6 +271,19 @@ async fn load_gltf<'a, 'b, 'c>(
 
     let linear_textures = get_linear_textures(&gltf.document);
 
+    let custom_vertex_attributes = if settings.custom_vertex_attributes.is_empty() {
+        Cow::Borrowed(&loader.custom_vertex_attributes)
+    } else {
+        let mut attributes = loader.custom_vertex_attributes.clone();
+        attributes.extend(
+            settings
+                .custom_vertex_attributes
+                .iter()
+                .map(|(name, attribute)| (name.clone(), *attribute)),
+        );
+        Cow::Owned(attributes)
+    };
+
     #[cfg(feature = "bevy_animation")]
     let paths = {
         let mut paths = HashMap::<usize, (usize, Vec<Name>)>::default();
@@ -609,17 +658,25 @@ async fn load_gltf<'a, 'b, 'c>(
                         error!("Skinned mesh {} used on both skinned and non skin nodes, this is likely to cause an error (NODE_SKINNED_MESH_WITHOUT_SKIN)", primitive_label);
                     }
                 }
-                match convert_attribute(
-                    semantic,
-                    accessor,
-                    &buffer_data,
-                    &loader.custom_vertex_attributes,
-                ) {
+                match convert_attribute(semantic, accessor, &buffer_data, &custom_vertex_attributes)
+                {
                     Ok((attribute, values)) => mesh.insert_attribute(attribute, values),
                     Err(err) => warn!("{}", err),
                 }
             }
 
+            if settings.flip_uvs {
+                for attribute in [Mesh::ATTRIBUTE_UV_0, Mesh::ATTRIBUTE_UV_1] {
+                    if let Some(VertexAttributeValues::Float32x2(uvs)) =
+                        mesh.attribute_mut(attribute)
+                    {
+                        for uv in uvs {
+                            uv[1] = 1.0 - uv[1];
+                        }
+                    }
+                }
+            }
+
             // Read vertex indices
             let reader = primitive.reader(|buffer| Some(buffer_data[buffer.index()].as_slice()));
             if let Some(indices) = reader.read_indices() {
@@ -679,7 +736,7 @@ async fn load_gltf<'a, 'b, 'c>(
             {
                 mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, vertex_attribute);
             } else if mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some()
-                && needs_tangents(&primitive.material())
+                && (settings.generate_tangents || needs_tangents(&primitive.material()))
             {
                 tracing::debug!(
                     "Missing vertex tangents for {}, computing them using the mikktspace algorithm. Consider using a tool such as Blender to pre-compute the tangents.", file_name
@@ -766,6 +823,14 @@ async fn load_gltf<'a, 'b, 'c>(
     // Then check for cycles.
     check_for_cycles(&gltf)?;
 
+    // Record the parent of every node, so the hierarchy can be walked in both directions.
+    let mut parents = HashMap::<usize, Handle<GltfNode>>::default();
+    for node in gltf.nodes() {
+        for child in node.children() {
+            parents.insert(child.index(), nodes[&node.index()].clone());
+        }
+    }
+
     // Now populate the nodes.
     for node in gltf.nodes() {
         let skin = node.skin().map(|skin| {
@@ -818,7 +883,7 @@ async fn load_gltf<'a, 'b, 'c>(
             .map(|mesh| mesh.index())
             .and_then(|i| meshes.get(i).cloned());
 
-        let gltf_node = GltfNode::new(
+        let mut gltf_node = GltfNode::new(
             &node,
             children,
             mesh,
@@ -826,6 +891,7 @@ async fn load_gltf<'a, 'b, 'c>(
             skin,
             node.extras().as_deref().map(GltfExtras::from),
         );
+        gltf_node.parent = parents.get(&node.index()).cloned();
 
         #[cfg(feature = "bevy_animation")]
         let gltf_node = gltf_node.with_animation_root(animation_roots.contains(&node.index()));
@@ -857,7 +923,10 @@ async fn load_gltf<'a, 'b, 'c>(
         let mut scene_load_context = load_context.begin_labeled_asset();
 
         let world_root_id = world
-            .spawn((Transform::default(), Visibility::default()))
+            .spawn((
+                Transform::from_scale(Vec3::splat(settings.scale)),
+                Visibility::default(),
+            ))
             .with_children(|parent| {
                 for node in scene.nodes() {
                     let result = load_node(
@@ -1045,7 +1114,7 @@ fn load_material(
             let color = pbr.base_color_factor();
             let base_color_channel = pbr
                 .base_color_texture()
-                .map(|info| uv_channel(material, "base color", info.tex_coord()))
+                .map(|info| uv_channel(material, "base color", texture_tex_coord(&info)))
                 .unwrap_or_default();
             let base_color_texture = pbr
                 .base_color_texture()
@@ -1058,7 +1127,7 @@ fn load_material(
 
             let normal_map_channel = material
                 .normal_texture()
-                .map(|info| uv_channel(material, "normal map", info.tex_coord()))
+                .map(|info| uv_channel(material, "normal map", texture_tex_coord(&info)))
                 .unwrap_or_default();
             let normal_map_texture: Option<Handle<Image>> =
                 material.normal_texture().map(|normal_texture| {
@@ -1068,7 +1137,7 @@ fn load_material(
 
             let metallic_roughness_channel = pbr
                 .metallic_roughness_texture()
-                .map(|info| uv_channel(material, "metallic/roughness", info.tex_coord()))
+                .map(|info| uv_channel(material, "metallic/roughness", texture_tex_coord(&info)))
                 .unwrap_or_default();
             let metallic_roughness_texture = pbr.metallic_roughness_texture().map(|info| {
                 warn_on_differing_texture_transforms(
@@ -1082,7 +1151,7 @@ fn load_material(
 
             let occlusion_channel = material
                 .occlusion_texture()
-                .map(|info| uv_channel(material, "occlusion", info.tex_coord()))
+                .map(|info| uv_channel(material, "occlusion", texture_tex_coord(&info)))
                 .unwrap_or_default();
             let occlusion_texture = material.occlusion_texture().map(|occlusion_texture| {
                 // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
@@ -1092,7 +1161,7 @@ fn load_material(
             let emissive = material.emissive_factor();
             let emissive_channel = material
                 .emissive_texture()
-                .map(|info| uv_channel(material, "emissive", info.tex_coord()))
+                .map(|info| uv_channel(material, "emissive", texture_tex_coord(&info)))
                 .unwrap_or_default();
             let emissive_texture = material.emissive_texture().map(|info| {
                 // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
@@ -1110,7 +1179,9 @@ fn load_material(
                 .map_or((0.0, UvChannel::Uv0, None), |transmission| {
                     let specular_transmission_channel = transmission
                         .transmission_texture()
-                        .map(|info| uv_channel(material, "specular/transmission", info.tex_coord()))
+                        .map(|info| {
+                            uv_channel(material, "specular/transmission", texture_tex_coord(&info))
+                        })
                         .unwrap_or_default();
                     let transmission_texture: Option<Handle<Image>> = transmission
                         .transmission_texture()
@@ -1142,7 +1213,7 @@ fn load_material(
                 |volume| {
                     let thickness_channel = volume
                         .thickness_texture()
-                        .map(|info| uv_channel(material, "thickness", info.tex_coord()))
+                        .map(|info| uv_channel(material, "thickness", texture_tex_coord(&info)))
                         .unwrap_or_default();
                     let thickness_texture: Option<Handle<Image>> =
                         volume.thickness_texture().map(|thickness_texture| {
@@ -1758,7 +1829,8 @@ struct MorphTargetNames {
 mod test {
     use std::path::Path;
 
-    use crate::{Gltf, GltfAssetLabel, GltfNode, GltfSkin};
+    use super::texture_tex_coord;
+    use crate::{Gltf, GltfAssetLabel, GltfLoaderSettings, GltfNode, GltfSkin};
     use bevy_app::{App, TaskPoolPlugin};
     use bevy_asset::{
         io::{
@@ -1767,10 +1839,18 @@ mod test {
         },
         AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState,
     };
-    use bevy_ecs::{resource::Resource, world::World};
+    use bevy_ecs::{hierarchy::ChildOf, query::Without, resource::Resource, world::World};
     use bevy_log::LogPlugin;
-    use bevy_render::mesh::{skinning::SkinnedMeshInverseBindposes, MeshPlugin};
-    use bevy_scene::ScenePlugin;
+    use bevy_math::Vec3;
+    use bevy_render::{
+        mesh::{
+            skinning::SkinnedMeshInverseBindposes, Mesh, MeshPlugin, MeshVertexAttribute,
+            VertexAttributeValues,
+        },
+        render_resource::VertexFormat,
+    };
+    use bevy_scene::{Scene, ScenePlugin};
+    use bevy_transform::components::Transform;
 
     fn test_app(dir: Dir) -> App {
         let mut app = App::new();
@@ -1808,6 +1888,14 @@ mod test {
     }
 
     fn load_gltf_into_app(gltf_path: &str, gltf: &str) -> App {
+        load_gltf_into_app_with_settings(gltf_path, gltf, |_| {})
+    }
+
+    fn load_gltf_into_app_with_settings(
+        gltf_path: &str,
+        gltf: &str,
+        settings: impl Fn(&mut GltfLoaderSettings) + Send + Sync + 'static,
+    ) -> App {
         #[expect(
             dead_code,
             reason = "This struct is used to keep the handle alive. As such, we have no need to handle the handle directly."
@@ -1820,7 +1908,7 @@ mod test {
         let mut app = test_app(dir);
         app.update();
         let asset_server = app.world().resource::<AssetServer>().clone();
-        let handle: Handle<Gltf> = asset_server.load(gltf_path.to_string());
+        let handle: Handle<Gltf> = asset_server.load_with_settings(gltf_path.to_string(), settings);
         let handle_id = handle.id();
         app.insert_resource(GltfHandle(handle));
         app.update();
@@ -2187,4 +2275,178 @@ mod test {
         assert_eq!(skinned_node.children.len(), 2);
         assert_eq!(skinned_node.skin.as_ref(), Some(&gltf_root.skins[0]));
     }
+
+    #[test]
+    fn loader_settings() {
+        const ATTRIBUTE_TEMPERATURE: MeshVertexAttribute =
+            MeshVertexAttribute::new("Temperature", 988540917, VertexFormat::Float32);
+
+        let mut app = load_gltf_into_app_with_settings(
+            "test.gltf",
+            r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "nodes": [
+        {
+            "mesh": 0
+        }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                {
+                    "attributes": {
+                        "POSITION": 0,
+                        "_TEMPERATURE": 1,
+                        "TEXCOORD_0": 2
+                    }
+                }
+            ]
+        }
+    ],
+    "buffers": [
+        {
+            "uri" : "data:application/gltf-buffer;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAACAPwAAAEAAAEBAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAIA/",
+            "byteLength" : 72
+        }
+    ],
+    "bufferViews": [
+        {
+            "buffer": 0,
+            "byteLength": 36
+        },
+        {
+            "buffer": 0,
+            "byteOffset": 36,
+            "byteLength": 12
+        },
+        {
+            "buffer": 0,
+            "byteOffset": 48,
+            "byteLength": 24
+        }
+    ],
+    "accessors": [
+        {
+            "bufferView" : 0,
+            "componentType" : 5126,
+            "count" : 3,
+            "type" : "VEC3",
+            "min": [0.0, 0.0, 0.0],
+            "max": [1.0, 1.0, 0.0]
+        },
+        {
+            "bufferView" : 1,
+            "componentType" : 5126,
+            "count" : 3,
+            "type" : "SCALAR"
+        },
+        {
+            "bufferView" : 2,
+            "componentType" : 5126,
+            "count" : 3,
+            "type" : "VEC2"
+        }
+    ],
+    "scene": 0,
+    "scenes": [{ "nodes": [0] }]
+}
+"#,
+            |settings| {
+                settings.generate_tangents = true;
+                settings.flip_uvs = true;
+                settings.scale = 0.01;
+                settings
+                    .custom_vertex_attributes
+                    .insert("_TEMPERATURE".into(), ATTRIBUTE_TEMPERATURE);
+            },
+        );
+
+        let (_, mesh) = app
+            .world()
+            .resource::<Assets<Mesh>>()
+            .iter()
+            .next()
+            .unwrap();
+        assert_eq!(
+            mesh.attribute(ATTRIBUTE_TEMPERATURE),
+            Some(&VertexAttributeValues::Float32(vec![1.0, 2.0, 3.0]))
+        );
+        assert_eq!(
+            mesh.attribute(Mesh::ATTRIBUTE_UV_0),
+            Some(&VertexAttributeValues::Float32x2(vec![
+                [0.0, 1.0],
+                [1.0, 1.0],
+                [0.0, 0.0]
+            ]))
+        );
+        // The material doesn't need tangents, so they're only generated because of the settings.
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_some());
+
+        let scene_handle = app
+            .world()
+            .resource::<Assets<Gltf>>()
+            .iter()
+            .next()
+            .unwrap()
+            .1
+            .scenes[0]
+            .clone();
+        let mut scenes = app.world_mut().resource_mut::<Assets<Scene>>();
+        let scene = scenes.get_mut(&scene_handle).unwrap();
+        let root_transform = scene
+            .world
+            .query_filtered::<&Transform, Without<ChildOf>>()
+            .single(&scene.world)
+            .unwrap();
+        assert_eq!(root_transform.scale, Vec3::splat(0.01));
+    }
+
+    #[test]
+    fn texture_transform_tex_coord() {
+        let gltf = gltf::Gltf::from_slice(
+            br#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "extensionsUsed": ["KHR_texture_transform"],
+    "images": [{ "uri": "texture.png" }],
+    "textures": [{ "source": 0 }],
+    "materials": [
+        {
+            "pbrMetallicRoughness": {
+                "baseColorTexture": {
+                    "index": 0,
+                    "extensions": { "KHR_texture_transform": { "texCoord": 1 } }
+                }
+            },
+            "normalTexture": {
+                "index": 0,
+                "extensions": { "KHR_texture_transform": { "texCoord": 1 } }
+            },
+            "occlusionTexture": {
+                "index": 0,
+                "texCoord": 1,
+                "extensions": { "KHR_texture_transform": { "scale": [2.0, 2.0] } }
+            },
+            "emissiveTexture": {
+                "index": 0
+            }
+        }
+    ]
+}
+"#,
+        )
+        .unwrap();
+        let material = gltf.materials().next().unwrap();
+        let pbr = material.pbr_metallic_roughness();
+
+        assert_eq!(texture_tex_coord(&pbr.base_color_texture().unwrap()), 1);
+        assert_eq!(texture_tex_coord(&material.normal_texture().unwrap()), 1);
+        assert_eq!(texture_tex_coord(&material.occlusion_texture().unwrap()), 1);
+        assert_eq!(texture_tex_coord(&material.emissive_texture().unwrap()), 0);
+    }
 }
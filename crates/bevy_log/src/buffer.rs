@@ -0,0 +1,165 @@
+use alloc::{collections::VecDeque, string::String, sync::Arc, vec::Vec};
+use bevy_ecs::resource::Resource;
+use core::fmt::{Debug, Write};
+use std::sync::{Mutex, PoisonError};
+use tracing::{field::Field, Event, Level, Subscriber};
+use tracing_subscriber::{field::Visit, layer::Context, Layer};
+
+/// A log event stored in the [`LogBuffer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// The level of the event.
+    pub level: Level,
+    /// The target of the event, usually the path of the module it was logged from.
+    pub target: String,
+    /// The message of the event, followed by its other fields.
+    pub message: String,
+}
+
+/// An in-memory ring buffer of the most recent log events, for example to display an in-game
+/// console.
+///
+/// The [`LogPlugin`](crate::LogPlugin) inserts this resource with a capacity of
+/// [`LogPlugin::log_buffer_capacity`](crate::LogPlugin::log_buffer_capacity). Only events that
+/// pass the [`LogFilter`](crate::LogFilter) are stored, and once the buffer is full the oldest
+/// event is dropped for every new one.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::LogBuffer;
+/// fn print_console(log_buffer: Res<LogBuffer>) {
+///     for record in log_buffer.records() {
+///         println!("[{}] {}: {}", record.level, record.target, record.message);
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct LogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    /// Creates an empty buffer holding up to `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of events held by the buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a copy of the stored events, from oldest to newest.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Adds an event to the buffer, dropping the oldest one if it is full.
+    pub fn push(&self, record: LogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Removes all stored events.
+    pub fn clear(&self) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Returns a [`Layer`] that stores the events it receives in this buffer.
+    ///
+    /// The [`LogPlugin`](crate::LogPlugin) already adds this layer for its own buffer.
+    pub fn layer(&self) -> LogBufferLayer {
+        LogBufferLayer {
+            buffer: self.clone(),
+        }
+    }
+}
+
+/// A [`Layer`] storing events in a [`LogBuffer`], created with [`LogBuffer::layer`].
+pub struct LogBufferLayer {
+    buffer: LogBuffer,
+}
+
+#[derive(Default)]
+struct MessageRecorder {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageRecorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            write!(self.fields, " {} = {:?};", field.name(), value).unwrap();
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut recorder = MessageRecorder::default();
+        event.record(&mut recorder);
+        recorder.message.push_str(&recorder.fields);
+
+        let meta = event.metadata();
+        self.buffer.push(LogRecord {
+            level: *meta.level(),
+            target: meta.target().into(),
+            message: recorder.message,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message: &str) -> LogRecord {
+        LogRecord {
+            level: Level::INFO,
+            target: "test".into(),
+            message: message.into(),
+        }
+    }
+
+    #[test]
+    fn drops_oldest_records() {
+        let buffer = LogBuffer::new(2);
+        buffer.push(record("a"));
+        buffer.push(record("b"));
+        assert_eq!(buffer.records(), [record("a"), record("b")]);
+
+        buffer.push(record("c"));
+        assert_eq!(buffer.capacity(), 2);
+        assert_eq!(buffer.records(), [record("b"), record("c")]);
+
+        buffer.clear();
+        assert!(buffer.records().is_empty());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let buffer = LogBuffer::new(0);
+        buffer.push(record("a"));
+        assert!(buffer.records().is_empty());
+    }
+}
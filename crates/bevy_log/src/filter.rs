@@ -0,0 +1,161 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::resource::Resource;
+use tracing::Level;
+use tracing_subscriber::{
+    filter::{Directive, ParseError},
+    layer::Layered,
+    registry::Registry,
+    reload::{self, Handle},
+    EnvFilter,
+};
+
+use crate::BoxedLayer;
+
+/// The subscriber the [`LogFilter`] is applied to by the [`LogPlugin`](crate::LogPlugin).
+pub(crate) type FilteredSubscriber = Layered<Option<BoxedLayer>, Registry>;
+
+/// Reflects and changes the [`EnvFilter`] used by the [`LogPlugin`](crate::LogPlugin) while
+/// the app is running.
+///
+/// The directives start out as the combination of [`LogPlugin::level`](crate::LogPlugin::level)
+/// and [`LogPlugin::filter`](crate::LogPlugin::filter), or the `RUST_LOG` environment variable
+/// if it is set.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::{Level, LogFilter};
+/// fn debug_physics(mut log_filter: ResMut<LogFilter>) {
+///     log_filter
+///         .set_level("my_game::physics", Level::DEBUG)
+///         .expect("valid module path");
+/// }
+/// ```
+#[derive(Resource)]
+pub struct LogFilter {
+    handle: Handle<EnvFilter, FilteredSubscriber>,
+    directives: Vec<Directive>,
+    text: String,
+}
+
+impl LogFilter {
+    pub(crate) fn new(handle: Handle<EnvFilter, FilteredSubscriber>, text: String) -> Self {
+        // The directives were already parsed by the filter, which ignored the invalid ones.
+        let directives = split_directives(&text)
+            .filter_map(|directive| directive.parse().ok())
+            .collect();
+        Self {
+            handle,
+            directives,
+            text,
+        }
+    }
+
+    /// Returns the current filter directives, in the [`EnvFilter`] format.
+    pub fn directives(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces all filter directives, given in the [`EnvFilter`] format.
+    ///
+    /// The directives are left unchanged if they can't be parsed.
+    pub fn set_directives(&mut self, directives: impl AsRef<str>) -> Result<(), ParseError> {
+        let directives = split_directives(directives.as_ref())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        self.apply(directives);
+        Ok(())
+    }
+
+    /// Sets the level of the events logged for a target, usually a crate or module path,
+    /// replacing any previous directive for the same target.
+    ///
+    /// Directives for the target that also filter on spans or fields are kept.
+    pub fn set_level(&mut self, target: &str, level: Level) -> Result<(), ParseError> {
+        let directive: Directive = format!("{target}={level}").parse()?;
+        // A directive only starts with the target and `=` if it has no span filter.
+        let prefix = format!("{target}=");
+        let mut directives: Vec<Directive> = self
+            .directives
+            .iter()
+            .filter(|directive| !directive.to_string().starts_with(&prefix))
+            .cloned()
+            .collect();
+        directives.push(directive);
+        self.apply(directives);
+        Ok(())
+    }
+
+    fn apply(&mut self, directives: Vec<Directive>) {
+        let filter = directives
+            .iter()
+            .cloned()
+            .fold(EnvFilter::default(), EnvFilter::add_directive);
+        // This only fails if the subscriber has been dropped, in which case nothing is logged.
+        _ = self.handle.reload(filter);
+        self.text = directives
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.directives = directives;
+    }
+}
+
+/// Splits a list of directives in the [`EnvFilter`] format, ignoring the commas separating the
+/// fields of a span filter.
+fn split_directives(directives: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    directives
+        .split(move |c| {
+            match c {
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            c == ',' && depth == 0
+        })
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log_filter(directives: &str) -> LogFilter {
+        // The layer is dropped, so the filter is never actually reloaded.
+        let (_, handle) = reload::Layer::new(EnvFilter::default());
+        LogFilter::new(handle, directives.into())
+    }
+
+    #[test]
+    fn set_level_replaces_target() {
+        let mut log_filter = log_filter("info,my_game=warn,my_game::physics=error");
+        log_filter.set_level("my_game", Level::DEBUG).unwrap();
+        assert_eq!(
+            log_filter.directives(),
+            "info,my_game::physics=error,my_game=debug"
+        );
+
+        log_filter.set_level("my_game", Level::TRACE).unwrap();
+        assert_eq!(
+            log_filter.directives(),
+            "info,my_game::physics=error,my_game=trace"
+        );
+    }
+
+    #[test]
+    fn set_level_keeps_span_filters() {
+        let mut log_filter = log_filter("warn,my_game[load{a=1,b=2}]=trace");
+        log_filter.set_level("my_game", Level::DEBUG).unwrap();
+
+        let directives: Vec<_> = split_directives(log_filter.directives()).collect();
+        assert_eq!(directives.len(), 3);
+        assert!(directives[1].starts_with("my_game[load{"));
+        assert!(directives[1].contains("a=1") && directives[1].contains("b=2"));
+        assert_eq!(directives[2], "my_game=debug");
+    }
+}
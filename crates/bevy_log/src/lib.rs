@@ -22,8 +22,13 @@ use core::error::Error;
 
 #[cfg(target_os = "android")]
 mod android_tracing;
+mod buffer;
+mod filter;
 mod once;
 
+pub use buffer::{LogBuffer, LogBufferLayer, LogRecord};
+pub use filter::LogFilter;
+
 #[cfg(feature = "trace_tracy_memory")]
 #[global_allocator]
 static GLOBAL: tracy_client::ProfiledAllocator<std::alloc::System> =
@@ -58,7 +63,7 @@ use tracing_subscriber::{
     filter::{FromEnvError, ParseError},
     prelude::*,
     registry::Registry,
-    EnvFilter, Layer,
+    reload, EnvFilter, Layer,
 };
 #[cfg(feature = "tracing-chrome")]
 use {
@@ -97,6 +102,7 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 ///             level: Level::DEBUG,
 ///             filter: "wgpu=error,bevy_render=info,bevy_ecs=trace".to_string(),
 ///             custom_layer: |_| None,
+///             log_buffer_capacity: 100,
 ///         }))
 ///         .run();
 /// }
@@ -240,6 +246,11 @@ pub struct LogPlugin {
     ///
     /// Please see the `examples/log_layers.rs` for a complete example.
     pub custom_layer: fn(app: &mut App) -> Option<BoxedLayer>,
+
+    /// The number of recent log events kept in the [`LogBuffer`] resource.
+    ///
+    /// Set this to `0` to disable the buffer. Defaults to `100`.
+    pub log_buffer_capacity: usize,
 }
 
 /// A boxed [`Layer`] that can be used with [`LogPlugin`].
@@ -254,6 +265,7 @@ impl Default for LogPlugin {
             filter: DEFAULT_FILTER.to_string(),
             level: Level::INFO,
             custom_layer: |_| None,
+            log_buffer_capacity: 100,
         }
     }
 }
@@ -289,8 +301,17 @@ impl Plugin for LogPlugin {
                 Ok::<EnvFilter, FromEnvError>(EnvFilter::builder().parse_lossy(&default_filter))
             })
             .unwrap();
+        // allow changing the filter at runtime through the `LogFilter` resource
+        let directives = filter_layer.to_string();
+        let (filter_layer, filter_handle) = reload::Layer::new(filter_layer);
+        app.insert_resource(LogFilter::new(filter_handle, directives));
         let subscriber = subscriber.with(filter_layer);
 
+        let log_buffer = LogBuffer::new(self.log_buffer_capacity);
+        let subscriber =
+            subscriber.with((self.log_buffer_capacity > 0).then(|| log_buffer.layer()));
+        app.insert_resource(log_buffer);
+
         #[cfg(feature = "trace")]
         let subscriber = subscriber.with(tracing_error::ErrorLayer::default());
 
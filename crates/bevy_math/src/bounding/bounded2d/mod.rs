@@ -95,6 +95,12 @@ impl Aabb2d {
         // Clamp point coordinates to the AABB
         point.clamp(self.min, self.max)
     }
+
+    /// Returns `true` if the given `point` is inside the AABB or on its boundary.
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
 }
 
 impl BoundingVolume for Aabb2d {
@@ -413,6 +419,17 @@ mod aabb2d_tests {
         );
     }
 
+    #[test]
+    fn contains_point() {
+        let aabb = Aabb2d {
+            min: Vec2::NEG_ONE,
+            max: Vec2::ONE,
+        };
+        assert!(aabb.contains_point(Vec2::new(0.25, 0.1)));
+        assert!(aabb.contains_point(Vec2::ONE));
+        assert!(!aabb.contains_point(Vec2::new(1.1, 0.0)));
+    }
+
     #[test]
     fn intersect_aabb() {
         let aabb = Aabb2d {
@@ -515,6 +532,12 @@ impl BoundingCircle {
     pub fn closest_point(&self, point: Vec2) -> Vec2 {
         self.circle.closest_point(point - self.center) + self.center
     }
+
+    /// Returns `true` if the given `point` is inside the bounding circle or on its boundary.
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.distance_squared(self.center) <= self.radius().squared()
+    }
 }
 
 impl BoundingVolume for BoundingCircle {
@@ -728,6 +751,14 @@ mod bounding_circle_tests {
         );
     }
 
+    #[test]
+    fn contains_point() {
+        let circle = BoundingCircle::new(Vec2::ONE, 1.0);
+        assert!(circle.contains_point(Vec2::new(1.25, 1.1)));
+        assert!(circle.contains_point(Vec2::new(2.0, 1.0)));
+        assert!(!circle.contains_point(Vec2::ZERO));
+    }
+
     #[test]
     fn intersect_bounding_circle() {
         let circle = BoundingCircle::new(Vec2::ZERO, 1.0);
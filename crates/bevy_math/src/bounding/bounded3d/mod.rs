@@ -103,6 +103,13 @@ impl Aabb3d {
         // Clamp point coordinates to the AABB
         point.into().clamp(self.min, self.max)
     }
+
+    /// Returns `true` if the given `point` is inside the AABB or on its boundary.
+    #[inline(always)]
+    pub fn contains_point(&self, point: impl Into<Vec3A>) -> bool {
+        let point = point.into();
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
 }
 
 impl BoundingVolume for Aabb3d {
@@ -419,6 +426,17 @@ mod aabb3d_tests {
         );
     }
 
+    #[test]
+    fn contains_point() {
+        let aabb = Aabb3d {
+            min: Vec3A::NEG_ONE,
+            max: Vec3A::ONE,
+        };
+        assert!(aabb.contains_point(Vec3A::new(0.25, 0.1, 0.3)));
+        assert!(aabb.contains_point(Vec3A::ONE));
+        assert!(!aabb.contains_point(Vec3A::new(0.0, 0.0, 1.1)));
+    }
+
     #[test]
     fn intersect_aabb() {
         let aabb = Aabb3d {
@@ -531,10 +549,16 @@ impl BoundingSphere {
         } else {
             // The point is outside the sphere.
             // Find the closest point on the surface of the sphere.
-            let dir_to_point = point / ops::sqrt(distance_squared);
+            let dir_to_point = (point - self.center) / ops::sqrt(distance_squared);
             self.center + radius * dir_to_point
         }
     }
+
+    /// Returns `true` if the given `point` is inside the bounding sphere or on its boundary.
+    #[inline(always)]
+    pub fn contains_point(&self, point: impl Into<Vec3A>) -> bool {
+        point.into().distance_squared(self.center) <= self.radius().squared()
+    }
 }
 
 impl BoundingVolume for BoundingSphere {
@@ -761,6 +785,17 @@ mod bounding_sphere_tests {
             sphere.closest_point(Vec3::new(0.25, 0.1, 0.3)),
             Vec3A::new(0.25, 0.1, 0.3)
         );
+
+        let sphere = BoundingSphere::new(Vec3::X * 2.0, 1.0);
+        assert_eq!(sphere.closest_point(Vec3::X * 10.0), Vec3A::X * 3.0);
+    }
+
+    #[test]
+    fn contains_point() {
+        let sphere = BoundingSphere::new(Vec3::ONE, 1.0);
+        assert!(sphere.contains_point(Vec3::new(1.25, 1.1, 1.3)));
+        assert!(sphere.contains_point(Vec3::new(2.0, 1.0, 1.0)));
+        assert!(!sphere.contains_point(Vec3::ZERO));
     }
 
     #[test]
@@ -4,7 +4,7 @@
 mod curve_impls;
 use crate::{
     ops::{self, FloatPow},
-    Vec2, VectorSpace,
+    NormedVectorSpace, Vec2, VectorSpace,
 };
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
@@ -1292,6 +1292,70 @@ impl<P: VectorSpace> CubicCurve<P> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<P: NormedVectorSpace> CubicCurve<P> {
+    /// Approximate the arc length of the curve by splitting it into `subdivisions` straight lines
+    /// and summing their lengths. More subdivisions give a more precise result.
+    pub fn arc_length(&self, subdivisions: usize) -> f32 {
+        self.iter_positions(subdivisions)
+            .tuple_windows()
+            .map(|(start, end)| start.distance(end))
+            .sum()
+    }
+
+    /// Iterate over the curve split into `subdivisions` of equal arc length, sampling the
+    /// position at each step.
+    ///
+    /// Unlike [`iter_positions`](Self::iter_positions), the returned points are evenly spaced
+    /// along the curve regardless of how fast it moves through `t`, which is useful for placing
+    /// objects along a path or moving at a constant speed. The arc length is approximated by
+    /// splitting the curve into `precision` straight lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `subdivisions` or `precision` is zero.
+    pub fn iter_positions_by_arc_length(
+        &self,
+        subdivisions: usize,
+        precision: usize,
+    ) -> impl Iterator<Item = P> + '_ {
+        assert!(
+            subdivisions > 0,
+            "the curve must be split into at least one subdivision"
+        );
+        assert!(
+            precision > 0,
+            "the arc length must be approximated with at least one line"
+        );
+        let ts: Vec<f32> = self.iter_uniformly(precision).collect();
+        let mut lengths = Vec::with_capacity(ts.len());
+        let mut total = 0.0;
+        let mut previous = self.position(0.0);
+        for &t in &ts {
+            let position = self.position(t);
+            total += previous.distance(position);
+            lengths.push(total);
+            previous = position;
+        }
+
+        (0..=subdivisions).map(move |i| {
+            let target = total * i as f32 / subdivisions as f32;
+            // Find the first sample reaching the target length, and interpolate `t` linearly
+            // between it and the previous one.
+            let index = lengths
+                .partition_point(|&length| length < target)
+                .clamp(1, lengths.len() - 1);
+            let (start, end) = (lengths[index - 1], lengths[index]);
+            let s = if end > start {
+                (target - start) / (end - start)
+            } else {
+                0.0
+            };
+            self.position(ts[index - 1] + (ts[index] - ts[index - 1]) * s)
+        })
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<P: VectorSpace> Extend<CubicSegment<P>> for CubicCurve<P> {
     fn extend<T: IntoIterator<Item = CubicSegment<P>>>(&mut self, iter: T) {
@@ -1834,4 +1898,53 @@ mod tests {
             );
         }
     }
+
+    /// Check that the arc length of a straight line is its length, and that points sampled by
+    /// arc length are evenly spaced even if the curve is not evenly parameterized.
+    #[test]
+    fn arc_length() {
+        let points = [[
+            vec2(0.0, 0.0),
+            vec2(0.5, 0.0),
+            vec2(1.0, 0.0),
+            vec2(9.0, 0.0),
+        ]];
+        let curve = CubicBezier::new(points).to_curve().unwrap();
+        assert!(ops::abs(curve.arc_length(100) - 9.0) < FLOAT_EQ);
+
+        let positions: Vec<Vec2> = curve.iter_positions_by_arc_length(3, 1000).collect();
+        assert_eq!(positions.len(), 4);
+        for (position, expected) in positions.iter().zip([0.0, 3.0, 6.0, 9.0]) {
+            assert!(
+                ops::abs(position.x - expected) < 1e-2,
+                "{position:?} is not close to {expected}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one subdivision")]
+    fn arc_length_zero_subdivisions() {
+        let points = [[
+            vec2(0.0, 0.0),
+            vec2(1.0, 0.0),
+            vec2(2.0, 0.0),
+            vec2(3.0, 0.0),
+        ]];
+        let curve = CubicBezier::new(points).to_curve().unwrap();
+        let _ = curve.iter_positions_by_arc_length(0, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one line")]
+    fn arc_length_zero_precision() {
+        let points = [[
+            vec2(0.0, 0.0),
+            vec2(1.0, 0.0),
+            vec2(2.0, 0.0),
+            vec2(3.0, 0.0),
+        ]];
+        let curve = CubicBezier::new(points).to_curve().unwrap();
+        let _ = curve.iter_positions_by_arc_length(3, 0);
+    }
 }
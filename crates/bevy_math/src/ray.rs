@@ -1,6 +1,6 @@
 use crate::{
     ops,
-    primitives::{InfinitePlane3d, Plane2d},
+    primitives::{InfinitePlane3d, Plane2d, Triangle3d},
     Dir2, Dir3, Vec2, Vec3,
 };
 
@@ -91,6 +91,39 @@ impl Ray3d {
         }
         None
     }
+
+    /// Get the distance to a triangle if the ray intersects it.
+    ///
+    /// Both sides of the triangle are hit. Degenerate triangles are never hit.
+    #[inline]
+    pub fn intersect_triangle(&self, triangle: &Triangle3d) -> Option<f32> {
+        // Möller–Trumbore intersection
+        let [a, b, c] = triangle.vertices;
+        let edge_ab = b - a;
+        let edge_ac = c - a;
+        let p = self.direction.cross(edge_ac);
+        let determinant = edge_ab.dot(p);
+        if ops::abs(determinant) <= f32::EPSILON {
+            // The ray is parallel to the triangle, or the triangle is degenerate.
+            return None;
+        }
+
+        let inverse_determinant = 1.0 / determinant;
+        let to_origin = self.origin - a;
+        let u = to_origin.dot(p) * inverse_determinant;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = to_origin.cross(edge_ab);
+        let v = self.direction.dot(q) * inverse_determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge_ac.dot(q) * inverse_determinant;
+        (distance > f32::EPSILON).then_some(distance)
+    }
 }
 
 #[cfg(test)]
@@ -179,4 +212,35 @@ mod tests {
             )
             .is_none());
     }
+
+    #[test]
+    fn intersect_triangle() {
+        let ray = Ray3d::new(Vec3::ZERO, Dir3::Z);
+        let triangle = Triangle3d::new(
+            Vec3::new(-1.0, -1.0, 2.0),
+            Vec3::new(1.0, -1.0, 2.0),
+            Vec3::new(0.0, 1.0, 2.0),
+        );
+        assert_eq!(ray.intersect_triangle(&triangle), Some(2.0));
+
+        // Back side
+        let reversed = Triangle3d::new(
+            triangle.vertices[2],
+            triangle.vertices[1],
+            triangle.vertices[0],
+        );
+        assert_eq!(ray.intersect_triangle(&reversed), Some(2.0));
+
+        // Behind the ray
+        let ray = Ray3d::new(Vec3::Z * 3.0, Dir3::Z);
+        assert!(ray.intersect_triangle(&triangle).is_none());
+
+        // Miss
+        let ray = Ray3d::new(Vec3::X * 2.0, Dir3::Z);
+        assert!(ray.intersect_triangle(&triangle).is_none());
+
+        // Parallel
+        let ray = Ray3d::new(Vec3::ZERO, Dir3::X);
+        assert!(ray.intersect_triangle(&triangle).is_none());
+    }
 }
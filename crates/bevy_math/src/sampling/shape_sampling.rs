@@ -572,6 +572,47 @@ impl<P: Primitive2d + Measured2d + ShapeSample<Output = Vec2>> ShapeSample for E
     }
 }
 
+impl ShapeSample for Torus {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let outer_radius = self.outer_radius();
+        if outer_radius <= 0.0 {
+            return Vec3::ZERO;
+        }
+        // Sample the cross-section of the tube, weighting points by their distance from the
+        // axis, since the volume they sweep out grows linearly with it.
+        let cross_section = Circle::new(self.minor_radius);
+        let Vec2 { x: offset, y } = loop {
+            let point = cross_section.sample_interior(rng);
+            if rng.gen_range(0.0..outer_radius) <= self.major_radius + point.x {
+                break point;
+            }
+        };
+        let angle = rng.gen_range(0.0..TAU);
+        let distance = self.major_radius + offset;
+        Vec3::new(distance * ops::cos(angle), y, distance * ops::sin(angle))
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let outer_radius = self.outer_radius();
+        if outer_radius <= 0.0 {
+            return Vec3::ZERO;
+        }
+        // Same as above, but for the boundary of the cross-section.
+        let cross_section = Circle::new(self.minor_radius);
+        let Vec2 { x: offset, y } = loop {
+            let point = cross_section.sample_boundary(rng);
+            if rng.gen_range(0.0..outer_radius) <= self.major_radius + point.x {
+                break point;
+            }
+        };
+        let angle = rng.gen_range(0.0..TAU);
+        let distance = self.major_radius + offset;
+        Vec3::new(distance * ops::cos(angle), y, distance * ops::sin(angle))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -637,4 +678,32 @@ mod tests {
             "samples will occur across all array items at statistically equal chance"
         );
     }
+
+    #[test]
+    fn torus_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let torus = Torus::new(1.0, 3.0);
+
+        // Distance of a point from the center of the tube.
+        let tube_distance = |point: Vec3| {
+            let from_axis = ops::sqrt(point.x * point.x + point.z * point.z);
+            ops::sqrt((from_axis - 2.0) * (from_axis - 2.0) + point.y * point.y)
+        };
+
+        let mut outer_hits = 0;
+        for _ in 0..5000 {
+            let point = torus.sample_interior(&mut rng);
+            assert!(tube_distance(point) <= 1.0 + 1e-5);
+
+            let point = torus.sample_boundary(&mut rng);
+            assert!(ops::abs(tube_distance(point) - 1.0) < 1e-4);
+            if point.x * point.x + point.z * point.z > 4.0 {
+                outer_hits += 1;
+            }
+        }
+
+        // The outer half of the surface makes up (2π + 2) / 4π of its area, so about 66% of
+        // boundary samples should land there.
+        assert!((3100..3500).contains(&outer_hits));
+    }
 }
@@ -16,6 +16,7 @@ mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
 mod scene;
+mod scene_diff;
 mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
@@ -31,6 +32,7 @@ pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
 pub use scene::*;
+pub use scene_diff::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
@@ -0,0 +1,257 @@
+use crate::{DynamicEntity, DynamicScene, SceneSpawnError};
+use bevy_ecs::{
+    entity::{hash_map::EntityHashMap, hash_set::EntityHashSet, Entity},
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::World,
+};
+use bevy_reflect::PartialReflect;
+
+/// The structural difference between two [`DynamicScene`]s, usually two snapshots of the same
+/// [`World`] taken with [`DynamicScene::from_world`].
+///
+/// Applying the diff to a world that mirrors the old snapshot brings it to the new one,
+/// which can be used for undo/redo history or to reconcile a client with a server.
+///
+/// Entities are matched by their id, and components by their type path.
+/// Resources are not compared.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::entity::hash_map::EntityHashMap;
+/// # use bevy_scene::{DynamicScene, SceneDiff};
+/// fn sync(
+///     source: &World,
+///     mirror: &mut World,
+///     previous: &DynamicScene,
+///     entity_map: &mut EntityHashMap<Entity>,
+/// ) {
+///     let current = DynamicScene::from_world(source);
+///     let diff = SceneDiff::new(previous, &current);
+///     diff.apply(mirror, entity_map).unwrap();
+/// }
+/// ```
+#[derive(Default)]
+pub struct SceneDiff {
+    /// Entities only present in the new scene, with all of their components.
+    pub spawned: Vec<DynamicEntity>,
+    /// Entities only present in the old scene.
+    pub despawned: Vec<Entity>,
+    /// Components that were added or changed on entities present in both scenes.
+    pub changed: Vec<DynamicEntity>,
+    /// The type paths of the components removed from entities present in both scenes.
+    pub removed: Vec<(Entity, Vec<String>)>,
+}
+
+impl SceneDiff {
+    /// Computes the changes needed to turn `old` into `new`.
+    ///
+    /// Components are compared with [`PartialReflect::reflect_partial_eq`],
+    /// and are considered changed if they can't be compared.
+    pub fn new(old: &DynamicScene, new: &DynamicScene) -> Self {
+        let old_entities: EntityHashMap<&DynamicEntity> = old
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect();
+        let new_entities: EntityHashSet = new.entities.iter().map(|entity| entity.entity).collect();
+
+        let mut diff = Self::default();
+        for new_entity in &new.entities {
+            let Some(old_entity) = old_entities.get(&new_entity.entity) else {
+                diff.spawned.push(clone_entity(new_entity));
+                continue;
+            };
+
+            let changed: Vec<_> = new_entity
+                .components
+                .iter()
+                .filter(|component| {
+                    let path = type_path(&***component);
+                    !old_entity.components.iter().any(|old_component| {
+                        type_path(&**old_component) == path
+                            && old_component.reflect_partial_eq(&***component) == Some(true)
+                    })
+                })
+                .map(|component| component.clone_value())
+                .collect();
+            if !changed.is_empty() {
+                diff.changed.push(DynamicEntity {
+                    entity: new_entity.entity,
+                    components: changed,
+                });
+            }
+
+            let removed: Vec<_> = old_entity
+                .components
+                .iter()
+                .map(|component| type_path(&**component))
+                .filter(|path| {
+                    !new_entity
+                        .components
+                        .iter()
+                        .any(|component| type_path(&**component) == *path)
+                })
+                .map(String::from)
+                .collect();
+            if !removed.is_empty() {
+                diff.removed.push((new_entity.entity, removed));
+            }
+        }
+
+        diff.despawned = old
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .filter(|entity| !new_entities.contains(entity))
+            .collect();
+        diff
+    }
+
+    /// Returns `true` if both scenes had the same entities and components.
+    pub fn is_empty(&self) -> bool {
+        self.spawned.is_empty()
+            && self.despawned.is_empty()
+            && self.changed.is_empty()
+            && self.removed.is_empty()
+    }
+
+    /// Applies the diff to the given world, using the world's [`AppTypeRegistry`].
+    ///
+    /// `entity_map` maps the entities of the scenes to the entities of `world`, as filled in by
+    /// [`DynamicScene::write_to_world`], and is updated with the spawned and despawned entities.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a component type is not registered
+    /// or doesn't reflect the [`Component`](bevy_ecs::component::Component) trait.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+
+        for entity in &self.despawned {
+            if let Some(entity) = entity_map.remove(entity) {
+                if let Ok(entity) = world.get_entity_mut(entity) {
+                    entity.despawn();
+                }
+            }
+        }
+
+        {
+            let registry = type_registry.read();
+            for (entity, type_paths) in &self.removed {
+                let Some(&entity) = entity_map.get(entity) else {
+                    continue;
+                };
+                for type_path in type_paths {
+                    let registration = registry.get_with_type_path(type_path).ok_or_else(|| {
+                        SceneSpawnError::UnregisteredButReflectedType {
+                            type_path: type_path.clone(),
+                        }
+                    })?;
+                    let reflect_component =
+                        registration.data::<ReflectComponent>().ok_or_else(|| {
+                            SceneSpawnError::UnregisteredComponent {
+                                type_path: type_path.clone(),
+                            }
+                        })?;
+                    if let Ok(mut entity) = world.get_entity_mut(entity) {
+                        reflect_component.remove(&mut entity);
+                    }
+                }
+            }
+        }
+
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: self
+                .spawned
+                .iter()
+                .chain(&self.changed)
+                .map(clone_entity)
+                .collect(),
+        };
+        scene.write_to_world_with(world, entity_map, &type_registry)
+    }
+}
+
+fn type_path(value: &dyn PartialReflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |info| info.type_path())
+}
+
+fn clone_entity(entity: &DynamicEntity) -> DynamicEntity {
+    DynamicEntity {
+        entity: entity.entity,
+        components: entity
+            .components
+            .iter()
+            .map(|component| component.clone_value())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::hash_map::EntityHashMap,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    use crate::{DynamicScene, SceneDiff};
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Marker;
+
+    #[test]
+    fn diff_and_apply() {
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<Health>();
+            registry.register::<Marker>();
+        }
+
+        let mut source = World::new();
+        source.insert_resource(type_registry.clone());
+        let kept = source.spawn((Health(10), Marker)).id();
+        let removed = source.spawn(Health(5)).id();
+        let old = DynamicScene::from_world(&source);
+
+        let mut mirror = World::new();
+        mirror.insert_resource(type_registry);
+        let mut entity_map = EntityHashMap::default();
+        old.write_to_world(&mut mirror, &mut entity_map).unwrap();
+        let mirrored_removed = entity_map[&removed];
+
+        source
+            .entity_mut(kept)
+            .insert(Health(20))
+            .remove::<Marker>();
+        source.despawn(removed);
+        let spawned = source.spawn(Marker).id();
+        let new = DynamicScene::from_world(&source);
+
+        let diff = SceneDiff::new(&old, &new);
+        assert_eq!(diff.despawned, vec![removed]);
+        assert_eq!(diff.spawned.len(), 1);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.removed.len(), 1);
+        assert!(SceneDiff::new(&new, &new).is_empty());
+
+        diff.apply(&mut mirror, &mut entity_map).unwrap();
+        assert!(mirror.get_entity(mirrored_removed).is_err());
+        assert_eq!(mirror.get::<Health>(entity_map[&kept]), Some(&Health(20)));
+        assert!(!mirror.entity(entity_map[&kept]).contains::<Marker>());
+        assert!(mirror.entity(entity_map[&spawned]).contains::<Marker>());
+    }
+}
@@ -1,5 +1,10 @@
 use bevy_app::{App, MainScheduleOrder, Plugin, PreStartup, PreUpdate, SubApp};
-use bevy_ecs::{event::Events, schedule::IntoSystemConfigs, world::FromWorld};
+use bevy_ecs::{
+    event::Events,
+    schedule::IntoSystemConfigs,
+    system::{IntoSystem, SystemId, SystemInput},
+    world::FromWorld,
+};
 use bevy_utils::once;
 use log::warn;
 
@@ -8,7 +13,7 @@ use crate::{
         setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
         StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
     },
-    state_scoped::clear_state_scoped_entities,
+    state_scoped::{clear_state_scoped_entities, StateScoped},
 };
 
 #[cfg(feature = "bevy_reflect")]
@@ -65,6 +70,25 @@ pub trait AppExtStates {
     /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
 
+    /// Registers a one-shot system that is unregistered once the state `S` no longer matches
+    /// `state`, dropping its [`Local`](bevy_ecs::system::Local)s and change ticks with it.
+    ///
+    /// This keeps state such as a menu's cached `Local`s from leaking into the next session.
+    /// Like other [`StateScoped`](crate::state_scoped::StateScoped) entities, this requires
+    /// state-scoped entity clearing to be enabled for `S`, see
+    /// [`enable_state_scoped_entities`](Self::enable_state_scoped_entities).
+    ///
+    /// See [`App::register_system`] for more information on one-shot systems.
+    fn register_state_scoped_system<S, I, O, M>(
+        &mut self,
+        state: S,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        S: States,
+        I: SystemInput + 'static,
+        O: 'static;
+
     #[cfg(feature = "bevy_reflect")]
     /// Registers the state type `T` using [`App::register_type`],
     /// and adds [`ReflectState`](crate::reflect::ReflectState) type data to `T` in the type registry.
@@ -230,6 +254,23 @@ impl AppExtStates for SubApp {
         )
     }
 
+    fn register_state_scoped_system<S, I, O, M>(
+        &mut self,
+        state: S,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        S: States,
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.register_system(system);
+        self.world_mut()
+            .entity_mut(id.entity())
+            .insert(StateScoped(state));
+        id
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -281,6 +322,19 @@ impl AppExtStates for App {
         self
     }
 
+    fn register_state_scoped_system<S, I, O, M>(
+        &mut self,
+        state: S,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        S: States,
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        self.main_mut().register_state_scoped_system(state, system)
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -333,6 +387,31 @@ mod tests {
         C,
     }
 
+    #[test]
+    fn state_scoped_system() {
+        use crate::state::NextState;
+        use bevy_ecs::system::RegisteredSystemError;
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>();
+        app.enable_state_scoped_entities::<TestState>();
+        let id = app.register_state_scoped_system(TestState::A, || {});
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        assert!(world.run_system(id).is_ok());
+
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert!(matches!(
+            world.run_system(id),
+            Err(RegisteredSystemError::SystemIdNotRegistered(_))
+        ));
+    }
+
     #[test]
     fn insert_state_can_overwrite_init_state() {
         let mut app = App::new();
@@ -148,6 +148,21 @@ impl TaskPool {
         1
     }
 
+    /// Tasks are not tracked on the single-threaded task pool, so this always returns `0`.
+    pub fn pending_tasks(&self) -> usize {
+        0
+    }
+
+    /// Tasks are not tracked on the single-threaded task pool, so this always returns `0`.
+    pub fn queued_tasks(&self) -> usize {
+        0
+    }
+
+    /// Tasks are not tracked on the single-threaded task pool, so this always returns `0`.
+    pub fn running_tasks(&self) -> usize {
+        0
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -1,5 +1,11 @@
 use alloc::{boxed::Box, format, string::String, vec::Vec};
-use core::{future::Future, marker::PhantomData, mem, panic::AssertUnwindSafe};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    mem,
+    panic::AssertUnwindSafe,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use std::{
     thread::{self, JoinHandle},
     thread_local,
@@ -26,6 +32,50 @@ impl Drop for CallOnDrop {
     }
 }
 
+/// Counts the tasks spawned with [`TaskPool::spawn`], see [`TaskPool::queued_tasks`] and
+/// [`TaskPool::running_tasks`].
+#[derive(Debug, Default)]
+struct TaskCounters {
+    /// The tasks that haven't been polled yet.
+    queued: AtomicUsize,
+    /// The tasks that have been polled at least once, but haven't completed yet.
+    running: AtomicUsize,
+}
+
+/// Counts a task spawned with [`TaskPool::spawn`] as queued until it's first polled, and as
+/// running from then on until dropped along with its future, which also covers canceled and
+/// panicking tasks.
+struct CountedTask {
+    counters: Arc<TaskCounters>,
+    started: bool,
+}
+
+impl CountedTask {
+    fn new(counters: Arc<TaskCounters>) -> Self {
+        counters.queued.fetch_add(1, Ordering::Relaxed);
+        Self {
+            counters,
+            started: false,
+        }
+    }
+
+    fn start(&mut self) {
+        self.started = true;
+        self.counters.running.fetch_add(1, Ordering::Relaxed);
+        self.counters.queued.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Drop for CountedTask {
+    fn drop(&mut self) {
+        if self.started {
+            self.counters.running.fetch_sub(1, Ordering::Relaxed);
+        } else {
+            self.counters.queued.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
 /// Used to create a [`TaskPool`]
 #[derive(Default)]
 #[must_use]
@@ -136,6 +186,9 @@ pub struct TaskPool {
     /// The executor for the pool.
     executor: Arc<crate::executor::Executor<'static>>,
 
+    /// Statistics about the tasks spawned with [`TaskPool::spawn`].
+    counters: Arc<TaskCounters>,
+
     // The inner state of the pool.
     threads: Vec<JoinHandle<()>>,
     shutdown_tx: async_channel::Sender<()>,
@@ -216,6 +269,7 @@ impl TaskPool {
 
         Self {
             executor,
+            counters: Arc::default(),
             threads,
             shutdown_tx,
         }
@@ -226,6 +280,35 @@ impl TaskPool {
         self.threads.len()
     }
 
+    /// Returns the number of tasks spawned with [`TaskPool::spawn`] that have not yet completed
+    /// or been canceled, that is the sum of [`TaskPool::queued_tasks`] and
+    /// [`TaskPool::running_tasks`].
+    ///
+    /// Tasks spawned on a [`Scope`] or with [`TaskPool::spawn_local`] are not counted.
+    pub fn pending_tasks(&self) -> usize {
+        self.queued_tasks() + self.running_tasks()
+    }
+
+    /// Returns the number of tasks spawned with [`TaskPool::spawn`] that are waiting for a thread
+    /// of the pool to start them, which is the depth of the queue of the pool.
+    ///
+    /// Tasks spawned on a [`Scope`] or with [`TaskPool::spawn_local`] are not counted.
+    pub fn queued_tasks(&self) -> usize {
+        self.counters.queued.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of tasks spawned with [`TaskPool::spawn`] that have started but not yet
+    /// completed or been canceled.
+    ///
+    /// This includes the tasks that are waiting to be woken up, so it's an upper bound on the
+    /// number of busy threads rather than an exact count: tracking every poll of every task
+    /// would slow down all of them.
+    ///
+    /// Tasks spawned on a [`Scope`] or with [`TaskPool::spawn_local`] are not counted.
+    pub fn running_tasks(&self) -> usize {
+        self.counters.running.load(Ordering::Relaxed)
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -560,7 +643,14 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        // Only counted when spawned, first polled and dropped, so that the later polls of the task
+        // are free of bookkeeping.
+        let counted = CountedTask::new(self.counters.clone());
+        Task::new(self.executor.spawn(async move {
+            let mut counted = counted;
+            counted.start();
+            future.await
+        }))
     }
 
     /// Spawns a static future on the thread-local async executor for the
@@ -706,6 +796,30 @@ mod tests {
     use core::sync::atomic::{AtomicBool, AtomicI32, Ordering};
     use std::sync::Barrier;
 
+    #[test]
+    fn test_task_counters() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let (started_tx, started_rx) = async_channel::unbounded::<()>();
+        let (tx, rx) = async_channel::unbounded::<()>();
+
+        let running = pool.spawn(async move {
+            started_tx.send_blocking(()).unwrap();
+            // Block the only thread of the pool, so that the next task stays queued.
+            rx.recv_blocking().unwrap();
+        });
+        started_rx.recv_blocking().unwrap();
+        let queued = pool.spawn(async {});
+        assert_eq!(pool.running_tasks(), 1);
+        assert_eq!(pool.queued_tasks(), 1);
+        assert_eq!(pool.pending_tasks(), 2);
+
+        tx.send_blocking(()).unwrap();
+        block_on(running);
+        block_on(queued);
+        assert_eq!(pool.running_tasks(), 0);
+        assert_eq!(pool.queued_tasks(), 0);
+    }
+
     #[test]
     fn test_spawn() {
         let pool = TaskPool::new();
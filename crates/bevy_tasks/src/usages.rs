@@ -64,6 +64,9 @@ taskpool! {
     ///
     /// See [`TaskPool`] documentation for details on Bevy tasks.
     /// Use [`ComputeTaskPool`] if the work must be complete before advancing to the next frame.
+    ///
+    /// On Wasm, this pool isn't backed by web workers, so its tasks run on the main thread
+    /// between frames.
     (ASYNC_COMPUTE_TASK_POOL, AsyncComputeTaskPool)
 }
 
@@ -1,4 +1,8 @@
 use alloc::boxed::Box;
+use bevy_platform_support::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 use core::{
     any::Any,
     future::{Future, IntoFuture},
@@ -13,18 +17,27 @@ use futures_channel::oneshot;
 ///
 /// Tasks are also futures themselves and yield the output of the spawned future.
 #[derive(Debug)]
-pub struct Task<T>(oneshot::Receiver<Result<T, Panic>>);
+pub struct Task<T> {
+    receiver: oneshot::Receiver<Result<T, Panic>>,
+    finished: Arc<AtomicBool>,
+}
 
 impl<T: 'static> Task<T> {
     pub(crate) fn wrap_future(future: impl Future<Output = T> + 'static) -> Self {
         let (sender, receiver) = oneshot::channel();
+        let finished = Arc::new(AtomicBool::new(false));
+        let task_finished = finished.clone();
         wasm_bindgen_futures::spawn_local(async move {
             // Catch any panics that occur when polling the future so they can
             // be propagated back to the task handle.
             let value = CatchUnwind(AssertUnwindSafe(future)).await;
+            task_finished.store(true, Ordering::Release);
             let _ = sender.send(value);
         });
-        Self(receiver.into_future())
+        Self {
+            receiver: receiver.into_future(),
+            finished,
+        }
     }
 
     /// When building for Wasm, this method has no effect.
@@ -39,7 +52,7 @@ impl<T: 'static> Task<T> {
     /// When building for Wasm, it is not possible to cancel tasks, which means this is the same
     /// as just awaiting the task. This method is only included for feature parity with other platforms.
     pub async fn cancel(self) -> Option<T> {
-        match self.0.await {
+        match self.receiver.await {
             Ok(Ok(value)) => Some(value),
             Err(_) => None,
             Ok(Err(panic)) => {
@@ -50,12 +63,19 @@ impl<T: 'static> Task<T> {
             }
         }
     }
+
+    /// Returns `true` if the current task is finished.
+    ///
+    /// Unlike poll, it doesn't resolve the final value, it just checks if the task has finished.
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
 }
 
 impl<T> Future for Task<T> {
     type Output = T;
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match Pin::new(&mut self.0).poll(cx) {
+        match Pin::new(&mut self.receiver).poll(cx) {
             Poll::Ready(Ok(Ok(value))) => Poll::Ready(value),
             // NOTE: Propagating the panic here sorta has parity with the async_executor behavior.
             // For those tasks, polling them after a panic returns a `None` which gets `unwrap`ed, so
@@ -69,6 +69,8 @@ use crate::{time::Time, virt::Virtual};
 pub struct Fixed {
     timestep: Duration,
     overstep: Duration,
+    max_steps_per_update: Option<u32>,
+    steps_this_update: u32,
 }
 
 impl Time<Fixed> {
@@ -205,15 +207,66 @@ impl Time<Fixed> {
         self.context().overstep.as_secs_f64() / self.context().timestep.as_secs_f64()
     }
 
+    /// Returns the number of times the [`FixedMain`] schedule ran during the
+    /// last update.
+    #[inline]
+    pub fn steps_this_update(&self) -> u32 {
+        self.context().steps_this_update
+    }
+
+    /// Returns the maximum number of times the [`FixedMain`] schedule may run
+    /// during a single update, if any.
+    #[inline]
+    pub fn max_steps_per_update(&self) -> Option<u32> {
+        self.context().max_steps_per_update
+    }
+
+    /// Sets the maximum number of times the [`FixedMain`] schedule may run
+    /// during a single update.
+    ///
+    /// When the limit is reached, any whole timesteps left in the
+    /// [`overstep`](Self::overstep) are discarded and only the fractional part
+    /// is kept. This prevents a "spiral of death" where a long hitch makes the
+    /// fixed schedule run so many times that the next frame takes even longer.
+    ///
+    /// Note that discarding time makes [`elapsed()`](Time::elapsed) of
+    /// `Time<Fixed>` fall behind the one of [`Time<Virtual>`](Virtual).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is `Some(0)`.
+    #[inline]
+    pub fn set_max_steps_per_update(&mut self, max_steps: Option<u32>) {
+        assert_ne!(
+            max_steps,
+            Some(0),
+            "attempted to limit fixed steps per update to zero"
+        );
+        self.context_mut().max_steps_per_update = max_steps;
+    }
+
     fn accumulate(&mut self, delta: Duration) {
-        self.context_mut().overstep += delta;
+        let context = self.context_mut();
+        context.overstep += delta;
+        context.steps_this_update = 0;
     }
 
     fn expend(&mut self) -> bool {
         let timestep = self.timestep();
-        if let Some(new_value) = self.context_mut().overstep.checked_sub(timestep) {
+        let context = self.context_mut();
+        if context
+            .max_steps_per_update
+            .is_some_and(|max_steps| context.steps_this_update >= max_steps)
+        {
+            // drop the whole periods left in accumulated, keeping the fraction
+            let remainder = context.overstep.as_nanos() % timestep.as_nanos();
+            context.overstep = Duration::from_nanos(remainder as u64);
+            return false;
+        }
+        if let Some(new_value) = context.overstep.checked_sub(timestep) {
             // reduce accumulated and increase elapsed by period
-            self.context_mut().overstep = new_value;
+            context.overstep = new_value;
+            context.steps_this_update += 1;
             self.advance_by(timestep);
             true
         } else {
@@ -228,6 +281,8 @@ impl Default for Fixed {
         Self {
             timestep: Time::<Fixed>::DEFAULT_TIMESTEP,
             overstep: Duration::ZERO,
+            max_steps_per_update: None,
+            steps_this_update: 0,
         }
     }
 }
@@ -271,6 +326,30 @@ mod test {
         assert_eq!(time.timestep(), Duration::from_millis(125));
     }
 
+    #[test]
+    fn test_max_steps_per_update() {
+        let mut time = Time::<Fixed>::from_seconds(1.0);
+        time.set_max_steps_per_update(Some(2));
+
+        time.accumulate(Duration::from_millis(4500));
+
+        assert!(time.expend()); // true
+        assert!(time.expend()); // true
+        assert!(!time.expend()); // false
+
+        assert_eq!(time.steps_this_update(), 2);
+        assert_eq!(time.elapsed(), Duration::from_secs(2));
+        assert_eq!(time.overstep(), Duration::from_millis(500));
+
+        time.accumulate(Duration::from_millis(500));
+
+        assert_eq!(time.steps_this_update(), 0);
+        assert!(time.expend()); // true
+        assert!(!time.expend()); // false
+        assert_eq!(time.steps_this_update(), 1);
+        assert_eq!(time.overstep(), Duration::ZERO);
+    }
+
     #[test]
     fn test_expend() {
         let mut time = Time::<Fixed>::from_seconds(2.0);
@@ -16,6 +16,7 @@ extern crate alloc;
 pub mod common_conditions;
 mod fixed;
 mod real;
+mod scaled;
 mod stopwatch;
 mod time;
 mod timer;
@@ -23,6 +24,7 @@ mod virt;
 
 pub use fixed::*;
 pub use real::*;
+pub use scaled::*;
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
@@ -76,11 +78,16 @@ impl Plugin for TimePlugin {
                 .register_type::<Timer>();
         }
 
+        app.add_event::<TimerFinished>();
+
         app.add_systems(
             First,
-            time_system
-                .in_set(TimeSystem)
-                .ambiguous_with(event_update_system),
+            (
+                time_system
+                    .in_set(TimeSystem)
+                    .ambiguous_with(event_update_system),
+                tick_timers.after(TimeSystem),
+            ),
         )
         .add_systems(
             RunFixedMainLoop,
@@ -177,6 +184,7 @@ pub fn time_system(
 #[cfg(test)]
 mod tests {
     use crate::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
+    use alloc::vec::Vec;
     use bevy_app::{App, FixedUpdate, Startup, Update};
     use bevy_ecs::{
         event::{Event, EventReader, EventRegistry, EventWriter, Events, ShouldUpdateEvents},
@@ -229,6 +237,37 @@ mod tests {
         *frame_count += 1;
     }
 
+    #[test]
+    fn timer_components_send_finished_events() {
+        use crate::{Timer, TimerFinished, TimerMode};
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+        let repeating = app
+            .world_mut()
+            .spawn(Timer::from_seconds(0.1, TimerMode::Repeating))
+            .id();
+        app.world_mut()
+            .spawn(Timer::from_seconds(1.0, TimerMode::Once));
+
+        // The first update doesn't advance the time.
+        app.update();
+        app.update();
+
+        let events = app.world().resource::<Events<TimerFinished>>();
+        let finished: Vec<_> = events.get_cursor().read(events).copied().collect();
+        assert_eq!(
+            finished,
+            [TimerFinished {
+                entity: repeating,
+                times: 2
+            }]
+        );
+    }
+
     #[test]
     fn fixed_main_schedule_should_run_with_time_plugin_enabled() {
         // Set the time step to just over half the fixed update timestep
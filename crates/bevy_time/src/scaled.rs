@@ -0,0 +1,248 @@
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Res, ResMut},
+};
+use core::{fmt, marker::PhantomData, time::Duration};
+
+use crate::{time::Time, virt::Virtual, TimeSystem};
+
+/// An additional game clock that can be paused and scaled independently of
+/// [`Time<Virtual>`].
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Scaled<T>>#impl-Time<Scaled<T>>`].**
+///
+/// The marker type `T` distinguishes clocks from each other, so that a game
+/// can keep for example a world clock and an ability cooldown clock, each with
+/// its own time dilation. Clocks are added with [`ScaledTimePlugin`] and
+/// accessed as `Res<Time<Scaled<T>>>` or `ResMut<Time<Scaled<T>>>`.
+///
+/// Scaled clocks advance by the [`delta()`](Time::delta) of [`Time<Virtual>`],
+/// multiplied by their own [`relative_speed()`](Time::relative_speed). This
+/// means they are also stopped while the virtual clock is paused, and never
+/// advance by more than the virtual [`max_delta()`](Time::max_delta) scaled by
+/// their speed.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// # use bevy_time::{Scaled, ScaledTimePlugin};
+/// #[derive(Default)]
+/// struct Cooldowns;
+///
+/// fn slow_cooldowns(mut time: ResMut<Time<Scaled<Cooldowns>>>) {
+///     time.set_relative_speed(0.5);
+/// }
+///
+/// fn tick_cooldowns(time: Res<Time<Scaled<Cooldowns>>>) {
+///     let _delta = time.delta();
+/// }
+///
+/// App::new()
+///     .add_plugins(ScaledTimePlugin::<Cooldowns>::default())
+///     .add_systems(Startup, slow_cooldowns)
+///     .add_systems(Update, tick_cooldowns);
+/// ```
+pub struct Scaled<T> {
+    paused: bool,
+    relative_speed: f64,
+    effective_speed: f64,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Time<Scaled<T>> {
+    /// Returns the speed the clock advances relative to [`Time<Virtual>`], as [`f32`].
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed_f64() as f32
+    }
+
+    /// Returns the speed the clock advances relative to [`Time<Virtual>`], as [`f64`].
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    /// Returns the speed the clock advanced relative to [`Time<Virtual>`] in
+    /// this update, as [`f32`].
+    ///
+    /// Returns `0.0` if the clock was paused or what the `relative_speed` value
+    /// was at the start of this update.
+    #[inline]
+    pub fn effective_speed(&self) -> f32 {
+        self.context().effective_speed as f32
+    }
+
+    /// Returns the speed the clock advanced relative to [`Time<Virtual>`] in
+    /// this update, as [`f64`].
+    ///
+    /// Returns `0.0` if the clock was paused or what the `relative_speed` value
+    /// was at the start of this update.
+    #[inline]
+    pub fn effective_speed_f64(&self) -> f64 {
+        self.context().effective_speed
+    }
+
+    /// Sets the speed the clock advances relative to [`Time<Virtual>`], given as an [`f32`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        self.set_relative_speed_f64(ratio as f64);
+    }
+
+    /// Sets the speed the clock advances relative to [`Time<Virtual>`], given as an [`f64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed_f64(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.context_mut().relative_speed = ratio;
+    }
+
+    /// Stops the clock, preventing it from advancing until resumed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    /// Resumes the clock if paused.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    /// Pauses the clock if it's running, or resumes it if it's paused.
+    #[inline]
+    pub fn toggle(&mut self) {
+        self.context_mut().paused ^= true;
+    }
+
+    /// Returns `true` if the clock is currently paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    /// Returns `true` if the clock was paused at the start of this update.
+    #[inline]
+    pub fn was_paused(&self) -> bool {
+        self.context().effective_speed == 0.0
+    }
+
+    /// Advances the clock by `virtual_delta` scaled by its relative speed.
+    fn advance_with_virtual_delta(&mut self, virtual_delta: Duration) {
+        let effective_speed = if self.context().paused {
+            0.0
+        } else {
+            self.context().relative_speed
+        };
+        let delta = if effective_speed != 1.0 {
+            virtual_delta.mul_f64(effective_speed)
+        } else {
+            // avoid rounding when at normal speed
+            virtual_delta
+        };
+        self.context_mut().effective_speed = effective_speed;
+        self.advance_by(delta);
+    }
+}
+
+impl<T> Default for Scaled<T> {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            relative_speed: 1.0,
+            effective_speed: 1.0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Scaled<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Scaled<T> {}
+
+impl<T> fmt::Debug for Scaled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scaled")
+            .field("paused", &self.paused)
+            .field("relative_speed", &self.relative_speed)
+            .field("effective_speed", &self.effective_speed)
+            .finish()
+    }
+}
+
+/// Adds a [`Time<Scaled<T>>`] clock to the app and advances it each frame,
+/// right after [`TimeSystem`].
+pub struct ScaledTimePlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for ScaledTimePlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: 'static> Plugin for ScaledTimePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Time<Scaled<T>>>()
+            .add_systems(First, update_scaled_time::<T>.after(TimeSystem));
+    }
+}
+
+/// Advances [`Time<Scaled<T>>`] based on the elapsed [`Time<Virtual>`].
+pub fn update_scaled_time<T: 'static>(
+    mut scaled: ResMut<Time<Scaled<T>>>,
+    virt: Res<Time<Virtual>>,
+) {
+    scaled.advance_with_virtual_delta(virt.delta());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Cooldowns;
+
+    #[test]
+    fn test_relative_speed() {
+        let mut time = Time::<Scaled<Cooldowns>>::default();
+
+        time.advance_with_virtual_delta(Duration::from_millis(250));
+        assert_eq!(time.delta(), Duration::from_millis(250));
+        assert_eq!(time.effective_speed(), 1.0);
+
+        time.set_relative_speed(0.5);
+        time.advance_with_virtual_delta(Duration::from_millis(250));
+        assert_eq!(time.delta(), Duration::from_millis(125));
+        assert_eq!(time.elapsed(), Duration::from_millis(375));
+        assert_eq!(time.effective_speed(), 0.5);
+    }
+
+    #[test]
+    fn test_pause() {
+        let mut time = Time::<Scaled<Cooldowns>>::default();
+
+        time.pause();
+        time.advance_with_virtual_delta(Duration::from_millis(250));
+        assert!(time.was_paused());
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.elapsed(), Duration::ZERO);
+
+        time.toggle();
+        time.advance_with_virtual_delta(Duration::from_millis(250));
+        assert!(!time.was_paused());
+        assert_eq!(time.elapsed(), Duration::from_millis(250));
+    }
+}
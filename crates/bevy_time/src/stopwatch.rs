@@ -29,6 +29,8 @@ use core::time::Duration;
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Default))]
 pub struct Stopwatch {
     elapsed: Duration,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    last_lap: Duration,
     is_paused: bool,
 }
 
@@ -202,5 +204,48 @@ impl Stopwatch {
     #[inline]
     pub fn reset(&mut self) {
         self.elapsed = Default::default();
+        self.last_lap = Default::default();
+    }
+
+    /// Returns the elapsed time since the last [`lap`](Stopwatch::lap), or since the last
+    /// [`reset`](Stopwatch::reset) if no lap was recorded since.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_time::*;
+    /// use std::time::Duration;
+    /// let mut stopwatch = Stopwatch::new();
+    /// stopwatch.tick(Duration::from_secs(2));
+    /// stopwatch.lap();
+    /// stopwatch.tick(Duration::from_secs(1));
+    /// assert_eq!(stopwatch.lap_elapsed(), Duration::from_secs(1));
+    /// assert_eq!(stopwatch.elapsed(), Duration::from_secs(3));
+    /// ```
+    #[inline]
+    pub fn lap_elapsed(&self) -> Duration {
+        self.elapsed.saturating_sub(self.last_lap)
+    }
+
+    /// Starts a new lap, returning the duration of the lap that just ended.
+    ///
+    /// Laps split the elapsed time into segments without affecting the total
+    /// [`elapsed`](Stopwatch::elapsed) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_time::*;
+    /// use std::time::Duration;
+    /// let mut stopwatch = Stopwatch::new();
+    /// stopwatch.tick(Duration::from_secs(2));
+    /// assert_eq!(stopwatch.lap(), Duration::from_secs(2));
+    /// stopwatch.tick(Duration::from_secs(1));
+    /// assert_eq!(stopwatch.lap(), Duration::from_secs(1));
+    /// assert_eq!(stopwatch.elapsed(), Duration::from_secs(3));
+    /// ```
+    #[inline]
+    pub fn lap(&mut self) -> Duration {
+        let lap = self.lap_elapsed();
+        self.last_lap = self.elapsed;
+        lap
     }
 }
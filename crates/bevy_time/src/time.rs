@@ -195,6 +195,9 @@ pub struct Time<T: Default = ()> {
     delta: Duration,
     delta_secs: f32,
     delta_secs_f64: f64,
+    delta_smoothing: f64,
+    delta_secs_smoothed: f32,
+    delta_secs_smoothed_f64: f64,
     elapsed: Duration,
     elapsed_secs: f32,
     elapsed_secs_f64: f64,
@@ -224,6 +227,14 @@ impl<T: Default> Time<T> {
         self.delta = delta;
         self.delta_secs = self.delta.as_secs_f32();
         self.delta_secs_f64 = self.delta.as_secs_f64();
+        self.delta_secs_smoothed_f64 = if self.elapsed.is_zero() {
+            // Nothing to average with yet, start from the first delta.
+            self.delta_secs_f64
+        } else {
+            self.delta_secs_smoothed_f64 * self.delta_smoothing
+                + self.delta_secs_f64 * (1.0 - self.delta_smoothing)
+        };
+        self.delta_secs_smoothed = self.delta_secs_smoothed_f64 as f32;
         self.elapsed += delta;
         self.elapsed_secs = self.elapsed.as_secs_f32();
         self.elapsed_secs_f64 = self.elapsed.as_secs_f64();
@@ -291,6 +302,62 @@ impl<T: Default> Time<T> {
         self.delta_secs_f64
     }
 
+    /// Returns the factor used to smooth [`delta`](#method.delta) over time.
+    ///
+    /// **Note:** The default factor is zero, meaning no smoothing is applied.
+    #[inline]
+    pub fn delta_smoothing(&self) -> f64 {
+        self.delta_smoothing
+    }
+
+    /// Sets the factor used to smooth [`delta`](#method.delta) over time.
+    ///
+    /// The smoothed delta is an exponential moving average: on each update it
+    /// keeps `smoothing` of its previous value and takes `1.0 - smoothing` of
+    /// the new delta. Higher values give steadier deltas that react more slowly
+    /// to changes in frame time, which can help physics and camera code recover
+    /// from loading hitches. To also limit the size of a single delta, see
+    /// [`Time<Virtual>::set_max_delta`](#method.set_max_delta).
+    ///
+    /// The generic [`Time`] resource copies this setting from the clock it
+    /// mirrors, so set it on [`Time<Virtual>`](crate::Virtual) for gameplay code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not in the `0.0..1.0` range.
+    #[inline]
+    pub fn set_delta_smoothing(&mut self, smoothing: f64) {
+        assert!(
+            (0.0..1.0).contains(&smoothing),
+            "delta smoothing must be in the range 0.0..1.0"
+        );
+        self.delta_smoothing = smoothing;
+    }
+
+    /// Returns the smoothed [`delta`](#method.delta), as [`Duration`].
+    ///
+    /// See [`set_delta_smoothing`](#method.set_delta_smoothing).
+    #[inline]
+    pub fn delta_smoothed(&self) -> Duration {
+        Duration::from_secs_f64(self.delta_secs_smoothed_f64)
+    }
+
+    /// Returns the smoothed [`delta`](#method.delta), as [`f32`] seconds.
+    ///
+    /// See [`set_delta_smoothing`](#method.set_delta_smoothing).
+    #[inline]
+    pub fn delta_secs_smoothed(&self) -> f32 {
+        self.delta_secs_smoothed
+    }
+
+    /// Returns the smoothed [`delta`](#method.delta), as [`f64`] seconds.
+    ///
+    /// See [`set_delta_smoothing`](#method.set_delta_smoothing).
+    #[inline]
+    pub fn delta_secs_smoothed_f64(&self) -> f64 {
+        self.delta_secs_smoothed_f64
+    }
+
     /// Returns how much time has advanced since [`startup`](#method.startup), as [`Duration`].
     #[inline]
     pub fn elapsed(&self) -> Duration {
@@ -358,6 +425,9 @@ impl<T: Default> Time<T> {
             delta: self.delta,
             delta_secs: self.delta_secs,
             delta_secs_f64: self.delta_secs_f64,
+            delta_smoothing: self.delta_smoothing,
+            delta_secs_smoothed: self.delta_secs_smoothed,
+            delta_secs_smoothed_f64: self.delta_secs_smoothed_f64,
             elapsed: self.elapsed,
             elapsed_secs: self.elapsed_secs,
             elapsed_secs_f64: self.elapsed_secs_f64,
@@ -376,6 +446,9 @@ impl<T: Default> Default for Time<T> {
             delta: Duration::ZERO,
             delta_secs: 0.0,
             delta_secs_f64: 0.0,
+            delta_smoothing: 0.0,
+            delta_secs_smoothed: 0.0,
+            delta_secs_smoothed_f64: 0.0,
             elapsed: Duration::ZERO,
             elapsed_secs: 0.0,
             elapsed_secs_f64: 0.0,
@@ -444,6 +517,22 @@ mod test {
         assert_eq!(time.elapsed_secs_f64(), 0.75);
     }
 
+    #[test]
+    fn test_delta_smoothing() {
+        let mut time: Time = Time::default();
+        time.set_delta_smoothing(0.5);
+
+        time.advance_by(Duration::from_millis(100));
+        assert_eq!(time.delta_smoothed(), Duration::from_millis(100));
+
+        time.advance_by(Duration::from_millis(300));
+        assert_eq!(time.delta_secs_smoothed_f64(), 0.2);
+
+        time.advance_by(Duration::from_millis(200));
+        assert_eq!(time.delta_secs_smoothed_f64(), 0.2);
+        assert_eq!(time.delta(), Duration::from_millis(200));
+    }
+
     #[test]
     fn test_advance_to() {
         let mut time: Time = Time::default();
@@ -1,4 +1,12 @@
-use crate::Stopwatch;
+use crate::{Stopwatch, Time};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    system::{Query, Res},
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
 use core::time::Duration;
@@ -12,9 +20,12 @@ use core::time::Duration;
 /// Paused timers will not have elapsed time increased.
 ///
 /// Note that in order to advance the timer [`tick`](Timer::tick) **MUST** be called.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+///
+/// When used as a component, the timer is ticked automatically by [`tick_timers`], which sends a
+/// [`TimerFinished`] event every time it finishes.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
-#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Default))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component, Default))]
 pub struct Timer {
     stopwatch: Stopwatch,
     duration: Duration,
@@ -446,6 +457,37 @@ pub enum TimerMode {
     Repeating,
 }
 
+/// Sent by [`tick_timers`] when a [`Timer`] component finishes.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerFinished {
+    /// The entity of the timer.
+    pub entity: Entity,
+    /// How many times the timer finished during this tick, which can be more than one for
+    /// repeating timers shorter than the frame time.
+    pub times: u32,
+}
+
+/// Ticks every [`Timer`] component with the delta of [`Time`], sending a [`TimerFinished`] event
+/// for each timer that finished.
+///
+/// This is added to [`First`](bevy_app::First) by the [`TimePlugin`](crate::TimePlugin), after
+/// [`TimeSystem`](crate::TimeSystem).
+pub fn tick_timers(
+    time: Res<Time>,
+    mut timers: Query<(Entity, &mut Timer)>,
+    mut finished: EventWriter<TimerFinished>,
+) {
+    for (entity, mut timer) in &mut timers {
+        timer.tick(time.delta());
+        if timer.just_finished() {
+            finished.write(TimerFinished {
+                entity,
+                times: timer.times_finished_this_tick(),
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +215,14 @@ impl Time<Virtual> {
         self.context_mut().paused = false;
     }
 
+    /// Pauses the clock if it's running, or resumes it if it's paused.
+    ///
+    /// This is convenient for pause menus bound to a single key.
+    #[inline]
+    pub fn toggle(&mut self) {
+        self.context_mut().paused ^= true;
+    }
+
     /// Returns `true` if the clock is currently paused.
     #[inline]
     pub fn is_paused(&self) -> bool {
@@ -352,6 +360,23 @@ mod test {
         assert_eq!(time.elapsed(), Duration::from_millis(875));
     }
 
+    #[test]
+    fn test_toggle() {
+        let mut time = Time::<Virtual>::default();
+
+        time.toggle();
+        assert!(time.is_paused());
+
+        time.advance_with_raw_delta(Duration::from_millis(250));
+        assert_eq!(time.delta(), Duration::ZERO);
+
+        time.toggle();
+        assert!(!time.is_paused());
+
+        time.advance_with_raw_delta(Duration::from_millis(250));
+        assert_eq!(time.delta(), Duration::from_millis(250));
+    }
+
     #[test]
     fn test_pause() {
         let mut time = Time::<Virtual>::default();
@@ -1,7 +1,7 @@
 //! Showcase how to use and configure FPS overlay.
 
 use bevy::{
-    dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin},
+    dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin, FrameTimeGraphConfig},
     prelude::*,
     text::FontSmoothing,
 };
@@ -33,6 +33,15 @@ fn main() {
                     // We can also set the refresh interval for the FPS counter
                     refresh_interval: core::time::Duration::from_millis(100),
                     enabled: true,
+                    // We can also toggle the overlay with a key
+                    toggle_key: Some(KeyCode::F3),
+                    // We can show the number of entities
+                    show_entity_count: true,
+                    // And a graph of the recent frame times
+                    frame_time_graph_config: FrameTimeGraphConfig {
+                        enabled: true,
+                        ..default()
+                    },
                 },
             },
         ))
@@ -52,7 +61,8 @@ fn setup(mut commands: Commands) {
             "Press 1 to toggle the overlay color.\n",
             "Press 2 to decrease the overlay size.\n",
             "Press 3 to increase the overlay size.\n",
-            "Press 4 to toggle the overlay visibility."
+            "Press 4 or F3 to toggle the overlay visibility.\n",
+            "Press 5 to toggle the frame time graph."
         )),
         Node {
             position_type: PositionType::Absolute,
@@ -81,4 +91,7 @@ fn customize_config(input: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<FpsOve
     if input.just_pressed(KeyCode::Digit4) {
         overlay.enabled = !overlay.enabled;
     }
+    if input.just_pressed(KeyCode::Digit5) {
+        overlay.frame_time_graph_config.enabled = !overlay.frame_time_graph_config.enabled;
+    }
 }